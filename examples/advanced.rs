@@ -2,7 +2,10 @@
 //! and how to modify them at runtime
 
 use bevy::prelude::*;
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_panorbit_camera::{
+    Bindings, Limits, OrbitSettings, PanOrbitCamera, PanOrbitCameraPlugin, PanSettings,
+    ZoomSettings,
+};
 use std::f32::consts::TAU;
 
 fn main() {
@@ -55,24 +58,39 @@ fn setup(
             beta: Some(TAU / 8.0),
             radius: Some(5.0),
             // Set limits on rotation and zoom
-            alpha_upper_limit: Some(TAU / 4.0),
-            alpha_lower_limit: Some(-TAU / 4.0),
-            beta_upper_limit: Some(TAU / 3.0),
-            beta_lower_limit: Some(-TAU / 3.0),
-            zoom_upper_limit: Some(5.0),
-            zoom_lower_limit: Some(1.0),
-            // Adjust sensitivity of controls
-            orbit_sensitivity: 1.5,
-            pan_sensitivity: 0.5,
-            zoom_sensitivity: 0.5,
-            // Allow the camera to go upside down
-            allow_upside_down: true,
+            limits: Limits {
+                alpha_upper: Some(TAU / 4.0),
+                alpha_lower: Some(-TAU / 4.0),
+                beta_upper: Some(TAU / 3.0),
+                beta_lower: Some(-TAU / 3.0),
+                zoom_upper: Some(5.0),
+                zoom_lower: Some(1.0),
+                ..default()
+            },
+            orbit: OrbitSettings {
+                // Adjust sensitivity of controls
+                sensitivity: 1.5,
+                // Allow the camera to go upside down
+                allow_upside_down: true,
+                ..default()
+            },
+            pan: PanSettings {
+                sensitivity: 0.5,
+                ..default()
+            },
+            zoom: ZoomSettings {
+                sensitivity: 0.5,
+                // Reverse the zoom direction
+                reversed: true,
+                ..default()
+            },
             // Change the controls (these match Blender)
-            button_orbit: MouseButton::Middle,
-            button_pan: MouseButton::Middle,
-            modifier_pan: Some(KeyCode::ShiftLeft),
-            // Reverse the zoom direction
-            reversed_zoom: true,
+            bindings: Bindings {
+                button_orbit: MouseButton::Middle,
+                button_pan: MouseButton::Middle,
+                modifier_pan: Some(KeyCode::ShiftLeft),
+                ..default()
+            },
             ..default()
         },
     ));
@@ -1,7 +1,7 @@
 //! Demonstrates how you can animate the movement of the camera
 
 use bevy::prelude::*;
-use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_panorbit_camera::{OrbitSettings, PanOrbitCamera, PanOrbitCameraPlugin};
 use std::f32::consts::TAU;
 
 fn main() {
@@ -49,7 +49,10 @@ fn setup(
         },
         PanOrbitCamera {
             // Disable smoothing, since the animation takes care of that
-            orbit_smoothness: 0.0,
+            orbit: OrbitSettings {
+                smoothness: 0.0,
+                ..default()
+            },
             // Probably want to disable the controls
             enabled: false,
             ..default()
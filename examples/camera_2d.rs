@@ -0,0 +1,47 @@
+//! Demonstrates usage with a 2D camera, e.g. for a map tool. The camera can only pan and zoom.
+
+use bevy::prelude::*;
+use bevy_panorbit_camera::{Bindings, PanOrbitCamera, PanOrbitCameraPlugin, ZoomSettings};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    // A grid of tiles
+    for x in -5..=5 {
+        for y in -5..=5 {
+            let shade = if (x + y) % 2 == 0 { 0.3 } else { 0.5 };
+            commands.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(shade, shade + 0.2, shade),
+                    custom_size: Some(Vec2::splat(64.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x as f32 * 64.0, y as f32 * 64.0, 0.0),
+                ..default()
+            });
+        }
+    }
+    // Camera
+    commands.spawn((
+        Camera2dBundle::default(),
+        PanOrbitCamera {
+            // Orbiting is disabled for 2D cameras, so pan with the left mouse button instead
+            bindings: Bindings {
+                button_orbit: MouseButton::Right,
+                button_pan: MouseButton::Left,
+                ..default()
+            },
+            zoom: ZoomSettings {
+                to_cursor: true,
+                ..default()
+            },
+            ..default()
+        },
+    ));
+}
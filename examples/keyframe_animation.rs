@@ -0,0 +1,82 @@
+//! Demonstrates how to play back a keyframed camera animation, e.g. for a turntable render
+
+use bevy::prelude::*;
+use bevy_easings::EaseFunction;
+use bevy_panorbit_camera::{
+    OrbitSettings, PanOrbitAnimation, PanOrbitCamera, PanOrbitCameraPlugin, PanOrbitKeyframe,
+    PanSettings, ZoomSettings,
+};
+use std::f32::consts::TAU;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(PanOrbitCameraPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Plane::from_size(5.0).into()),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..default()
+    });
+    // Cube
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+        transform: Transform::from_xyz(0.0, 0.5, 0.0),
+        ..default()
+    });
+    // Light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // Camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
+            ..default()
+        },
+        PanOrbitCamera {
+            // Disable smoothing, since the animation takes care of that
+            orbit: OrbitSettings {
+                smoothness: 0.0,
+                ..default()
+            },
+            pan: PanSettings {
+                smoothness: 0.0,
+                ..default()
+            },
+            zoom: ZoomSettings {
+                smoothness: 0.0,
+                ..default()
+            },
+            // Probably want to disable the controls
+            enabled: false,
+            ..default()
+        },
+        PanOrbitAnimation::new(vec![
+            PanOrbitKeyframe::new(0.0, Vec3::ZERO, 0.0, 0.3, 5.0),
+            // Swoop in towards the cube
+            PanOrbitKeyframe::new(4.0, Vec3::Y * 0.5, TAU / 2.0, 0.1, 2.5)
+                .with_easing(EaseFunction::QuadraticInOut),
+            // And back out again
+            PanOrbitKeyframe::new(8.0, Vec3::ZERO, TAU, 0.3, 5.0)
+                .with_easing(EaseFunction::QuadraticInOut),
+        ])
+        .looping(),
+    ));
+}
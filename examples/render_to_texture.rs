@@ -173,6 +173,7 @@ fn setup(
         window_size: Some(Vec2::new(primary_window.width(), primary_window.height())),
         // Setting manual to true ensures PanOrbitCameraPlugin will not overwrite this resource
         manual: true,
+        dragging: false,
     });
 }
 
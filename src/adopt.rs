@@ -0,0 +1,121 @@
+use crate::{util, PanOrbitCamera};
+use bevy::prelude::*;
+
+/// Add this to an existing camera, e.g. one loaded from a glTF scene, to replace it with a
+/// `PanOrbitCamera` that starts exactly where the authored camera is, looking at the same
+/// point. The camera is detached from its parent, keeping its world position, and this component
+/// is removed once the `PanOrbitCamera` has been added. The camera's projection is kept, and any
+/// roll is removed.
+/// Wait until the camera's `GlobalTransform` is valid before adding this, i.e. at least one frame
+/// after the scene was spawned.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::PanOrbitAdopt;
+/// fn adopt_gltf_cameras(mut commands: Commands, cameras: Query<Entity, Added<Camera>>) {
+///     for entity in cameras.iter() {
+///         commands.entity(entity).insert(PanOrbitAdopt::at_distance(5.0));
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitAdopt {
+    /// The point to orbit around. If `None`, the focus is placed `distance` in front of the
+    /// camera.
+    pub focus: Option<Vec3>,
+    /// How far in front of the camera to place the focus when `focus` is `None`. Defaults to
+    /// `1.0`.
+    pub distance: f32,
+    /// The settings for the new camera. Its position values, i.e. the focus, alpha, beta, and
+    /// radius, are overwritten.
+    pub settings: PanOrbitCamera,
+}
+
+impl PanOrbitAdopt {
+    /// Orbit around the given point, with default settings.
+    pub fn with_focus(focus: Vec3) -> Self {
+        PanOrbitAdopt {
+            focus: Some(focus),
+            distance: 0.0,
+            settings: PanOrbitCamera::default(),
+        }
+    }
+
+    /// Orbit around the point `distance` in front of the camera, with default settings.
+    pub fn at_distance(distance: f32) -> Self {
+        PanOrbitAdopt {
+            focus: None,
+            distance,
+            settings: PanOrbitCamera::default(),
+        }
+    }
+}
+
+impl Default for PanOrbitAdopt {
+    fn default() -> Self {
+        PanOrbitAdopt::at_distance(1.0)
+    }
+}
+
+impl PanOrbitCamera {
+    /// A camera that starts at `transform`'s position and orbits around `focus`. The remaining
+    /// values are taken from `self`, and the camera is initialized as usual once it is spawned.
+    pub fn adopting(self, transform: &Transform, focus: Vec3) -> Self {
+        let (alpha, beta, radius) =
+            util::calculate_from_translation_and_focus(transform.translation, focus);
+        PanOrbitCamera {
+            focus,
+            alpha: Some(alpha),
+            beta: Some(beta),
+            radius: Some(radius),
+            initialized: false,
+            ..self
+        }
+    }
+}
+
+/// Replaces cameras marked with `PanOrbitAdopt` with a `PanOrbitCamera`
+pub(crate) fn adopt_cameras(
+    mut commands: Commands,
+    cameras: Query<(Entity, &GlobalTransform, &PanOrbitAdopt)>,
+) {
+    for (entity, global_transform, adopt) in cameras.iter() {
+        let transform = global_transform.compute_transform();
+        let focus = adopt
+            .focus
+            .unwrap_or(transform.translation + transform.forward() * adopt.distance);
+        commands
+            .entity(entity)
+            .remove_parent_in_place()
+            .remove::<PanOrbitAdopt>()
+            .insert(adopt.settings.adopting(&transform, focus));
+    }
+}
+
+#[cfg(test)]
+mod adopting_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn keeps_camera_position() {
+        let transform = Transform::from_xyz(3.0, 4.0, 5.0);
+        let focus = Vec3::new(3.0, 0.0, 2.0);
+        let camera = PanOrbitCamera::default().adopting(&transform, focus);
+
+        let mut result = Transform::default();
+        util::update_orbit_transform(
+            camera.alpha.unwrap(),
+            camera.beta.unwrap(),
+            0.0,
+            camera.radius.unwrap(),
+            camera.focus,
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            &mut result,
+        );
+        assert!(result.translation.abs_diff_eq(transform.translation, 1e-4));
+        assert!(approx_eq!(f32, camera.radius.unwrap(), 5.0, epsilon = 1e-5));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{util, Clocks, Easing, PanOrbitCamera};
+use bevy::prelude::*;
+use bevy_easings::Lerp;
+
+/// A single keyframe of a `PanOrbitAnimation`. Describes where the camera should be at a given
+/// point in time.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+pub struct PanOrbitKeyframe {
+    /// Time in seconds, relative to the start of the animation, at which the camera should reach
+    /// this keyframe.
+    pub time: f32,
+    /// The focus point at this keyframe.
+    pub focus: Vec3,
+    /// The alpha value (in radians) at this keyframe.
+    pub alpha: f32,
+    /// The beta value (in radians) at this keyframe.
+    pub beta: f32,
+    /// The radius at this keyframe.
+    pub radius: f32,
+    /// The easing function used when transitioning from the previous keyframe to this one.
+    /// If `None`, the transition is linear, which is what you want for e.g. a turntable.
+    /// Defaults to `None`.
+    pub easing: Option<Easing>,
+    /// If `true`, the camera moves from the previous keyframe to this one along the shortest arc
+    /// around the focus, at a constant angular speed, rather than changing alpha and beta
+    /// independently. Independent changes cut corners for large moves, e.g. through the model
+    /// when going from one side to the other over the top. Since the shortest way around is
+    /// taken, moves of more than half a turn need keyframes in between. Defaults to `false`.
+    pub arc: bool,
+}
+
+impl PanOrbitKeyframe {
+    /// Create a keyframe with linear easing.
+    pub fn new(time: f32, focus: Vec3, alpha: f32, beta: f32, radius: f32) -> Self {
+        PanOrbitKeyframe {
+            time,
+            focus,
+            alpha,
+            beta,
+            radius,
+            easing: None,
+            arc: false,
+        }
+    }
+
+    /// Set the easing function used when transitioning into this keyframe. Takes either an
+    /// `Easing` or a `bevy_easings::EaseFunction`.
+    pub fn with_easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = Some(easing.into());
+        self
+    }
+
+    /// Move along an arc around the focus when transitioning into this keyframe, see `arc`.
+    pub fn with_arc(mut self) -> Self {
+        self.arc = true;
+        self
+    }
+}
+
+/// Plays back a sequence of keyframes on a `PanOrbitCamera`, by driving its target values.
+/// Useful for turntable renders, guided tours, and the like. Playback uses the camera's
+/// `time_source`.
+/// Keyframes must be sorted by `time`.
+/// Note that the camera's smoothing is still applied on top of the animation, so you probably
+/// want to set the `PanOrbitCamera` smoothness values to `0.0`, and disable the controls using
+/// `PanOrbitCamera::enabled`.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitAnimation, PanOrbitCamera, PanOrbitKeyframe};
+/// # use std::f32::consts::TAU;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3dBundle::default(),
+///         PanOrbitCamera::default(),
+///         PanOrbitAnimation::new(vec![
+///             PanOrbitKeyframe::new(0.0, Vec3::ZERO, 0.0, 0.3, 5.0),
+///             PanOrbitKeyframe::new(10.0, Vec3::ZERO, TAU, 0.3, 5.0),
+///         ])
+///         .looping(),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitAnimation {
+    /// The keyframes to play, sorted by `time`.
+    pub keyframes: Vec<PanOrbitKeyframe>,
+    /// How far into the animation playback is, in seconds. Updated automatically.
+    pub elapsed: f32,
+    /// Whether to start again from the beginning after reaching the last keyframe.
+    /// Defaults to `false`.
+    pub looping: bool,
+    /// Whether the animation is currently playing. Automatically set to `false` once a
+    /// non-looping animation reaches the end.
+    pub playing: bool,
+}
+
+impl PanOrbitAnimation {
+    /// Create an animation from the given keyframes, which starts playing immediately.
+    pub fn new(keyframes: Vec<PanOrbitKeyframe>) -> Self {
+        PanOrbitAnimation {
+            keyframes,
+            elapsed: 0.0,
+            looping: false,
+            playing: true,
+        }
+    }
+
+    /// Create an animation that flies the camera from its current target values to the given
+    /// focus, alpha, beta, and radius over `duration` seconds, along an arc around the focus,
+    /// easing in and out. Useful for cinematic moves between viewpoints, where
+    /// `PanOrbitCommand::FlyTo` would cut through the model.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::{PanOrbitAnimation, PanOrbitCamera};
+    /// fn fly_to_back(mut commands: Commands, cameras: Query<(Entity, &PanOrbitCamera)>) {
+    ///     for (entity, pan_orbit) in cameras.iter() {
+    ///         let animation =
+    ///             PanOrbitAnimation::fly_to(pan_orbit, Vec3::ZERO, 3.0, 0.2, 5.0, 2.0);
+    ///         commands.entity(entity).insert(animation);
+    ///     }
+    /// }
+    /// ```
+    pub fn fly_to(
+        pan_orbit: &PanOrbitCamera,
+        focus: Vec3,
+        alpha: f32,
+        beta: f32,
+        radius: f32,
+        duration: f32,
+    ) -> Self {
+        PanOrbitAnimation::new(vec![
+            PanOrbitKeyframe::new(
+                0.0,
+                pan_orbit.target_focus,
+                pan_orbit.target_alpha,
+                pan_orbit.target_beta,
+                pan_orbit.target_radius,
+            ),
+            PanOrbitKeyframe::new(duration, focus, alpha, beta, radius)
+                .with_easing(Easing::CubicInOut)
+                .with_arc(),
+        ])
+    }
+
+    /// Make the animation loop.
+    pub fn looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// Resume playback.
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    /// Pause playback.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Jump back to the start of the animation.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.playing = true;
+    }
+
+    /// The total length of the animation in seconds, i.e. the time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    /// Calculate the camera state at the given time. Returns `None` if there are no keyframes.
+    pub fn sample(&self, time: f32) -> Option<PanOrbitKeyframe> {
+        let next_index = self.keyframes.iter().position(|k| k.time >= time);
+        match next_index {
+            None => self.keyframes.last().copied(),
+            Some(0) => self.keyframes.first().copied(),
+            Some(i) => {
+                let from = self.keyframes[i - 1];
+                let to = self.keyframes[i];
+                let mut t = (time - from.time) / (to.time - from.time);
+                if let Some(easing) = to.easing {
+                    t = easing.ease(t);
+                }
+                let (alpha, beta) = match to.arc {
+                    true => util::arc_angles(from.alpha, from.beta, to.alpha, to.beta, t),
+                    false => (from.alpha.lerp(&to.alpha, &t), from.beta.lerp(&to.beta, &t)),
+                };
+                Some(PanOrbitKeyframe {
+                    time,
+                    focus: from.focus.lerp(to.focus, t),
+                    alpha,
+                    beta,
+                    radius: from.radius.lerp(&to.radius, &t),
+                    easing: to.easing,
+                    arc: to.arc,
+                })
+            }
+        }
+    }
+}
+
+/// Advances every playing `PanOrbitAnimation` and applies the result to the target values of
+/// its `PanOrbitCamera`.
+pub(crate) fn animate_pan_orbit_cameras(
+    clocks: Clocks,
+    mut animations: Query<(&mut PanOrbitAnimation, &mut PanOrbitCamera)>,
+) {
+    for (mut animation, mut pan_orbit) in animations.iter_mut() {
+        if !animation.playing {
+            continue;
+        }
+
+        let duration = animation.duration();
+        animation.elapsed += clocks.animation_delta_seconds(&pan_orbit);
+        if animation.elapsed >= duration {
+            if animation.looping && duration > 0.0 {
+                animation.elapsed %= duration;
+            } else {
+                animation.elapsed = duration;
+                animation.playing = false;
+            }
+        }
+
+        if let Some(keyframe) = animation.sample(animation.elapsed) {
+            pan_orbit.target_focus = keyframe.focus;
+            pan_orbit.target_alpha = keyframe.alpha;
+            pan_orbit.target_beta = keyframe.beta;
+            pan_orbit.target_radius = keyframe.radius;
+        }
+    }
+}
+
+#[cfg(test)]
+mod sample_tests {
+    use super::*;
+
+    fn animation() -> PanOrbitAnimation {
+        PanOrbitAnimation::new(vec![
+            PanOrbitKeyframe::new(0.0, Vec3::ZERO, 0.0, 0.0, 2.0),
+            PanOrbitKeyframe::new(2.0, Vec3::X, 1.0, 0.5, 4.0),
+        ])
+    }
+
+    #[test]
+    fn empty_animation_returns_none() {
+        assert_eq!(PanOrbitAnimation::default().sample(1.0), None);
+    }
+
+    #[test]
+    fn interpolates_between_keyframes() {
+        let keyframe = animation().sample(1.0).unwrap();
+        assert_eq!(keyframe.focus, Vec3::X * 0.5);
+        assert_eq!(keyframe.alpha, 0.5);
+        assert_eq!(keyframe.beta, 0.25);
+        assert_eq!(keyframe.radius, 3.0);
+    }
+
+    #[test]
+    fn arc_goes_over_the_top() {
+        let animation = PanOrbitAnimation::new(vec![
+            PanOrbitKeyframe::new(0.0, Vec3::ZERO, 0.0, 0.5, 2.0),
+            PanOrbitKeyframe::new(2.0, Vec3::ZERO, 3.0, 0.5, 2.0).with_arc(),
+        ]);
+        // Independent interpolation would keep beta at 0.5 the whole way
+        assert!(animation.sample(1.0).unwrap().beta > 1.0);
+        assert_eq!(animation.sample(2.0).unwrap().alpha, 3.0);
+    }
+
+    #[test]
+    fn clamps_outside_of_keyframe_range() {
+        let animation = animation();
+        assert_eq!(animation.sample(-1.0).unwrap().alpha, 0.0);
+        assert_eq!(animation.sample(5.0).unwrap().alpha, 1.0);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{ActiveCameraData, Clocks, Easing, OrbitState, PanOrbitCamera};
+use bevy::prelude::*;
+
+/// Add this to an entity with `PanOrbitCamera` to smoothly move it from one `OrbitState` to
+/// another over time, e.g. for before/after comparisons or cinematic A to B moves. Call `reverse`
+/// to go back, which makes it easy to toggle between two views. The blend only drives the
+/// camera's target values, so once it finishes the camera can be orbited as usual.
+/// Playback uses the camera's `time_source`. The default blend isn't playing, so it does nothing
+/// until it's set up and `playing` is set.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitBlend {
+    /// The state to blend from.
+    pub from: OrbitState,
+    /// The state to blend to.
+    pub to: OrbitState,
+    /// How long the blend takes, in seconds.
+    pub duration: f32,
+    /// How far into the blend playback is, in seconds. Updated automatically.
+    pub elapsed: f32,
+    /// The easing function applied to the blend. If `None`, the blend is linear.
+    /// Defaults to `None`.
+    pub easing: Option<Easing>,
+    /// Whether the blend is currently playing. Automatically set to `false` once it reaches the
+    /// end.
+    pub playing: bool,
+}
+
+impl PanOrbitBlend {
+    /// Create a linear blend between two states, which starts playing immediately.
+    pub fn new(from: OrbitState, to: OrbitState, duration: f32) -> Self {
+        PanOrbitBlend {
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing: None,
+            playing: true,
+        }
+    }
+
+    /// Set the easing function. Takes either an `Easing` or a `bevy_easings::EaseFunction`.
+    pub fn with_easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = Some(easing.into());
+        self
+    }
+
+    /// Swap `from` and `to`, continuing from the current point of the blend, and start playing.
+    pub fn reverse(&mut self) {
+        std::mem::swap(&mut self.from, &mut self.to);
+        self.elapsed = (self.duration - self.elapsed).max(0.0);
+        self.playing = true;
+    }
+
+    /// The blend factor at the current point of playback, after easing.
+    pub fn t(&self) -> f32 {
+        let t = match self.duration > 0.0 {
+            true => (self.elapsed / self.duration).clamp(0.0, 1.0),
+            false => 1.0,
+        };
+        match self.easing {
+            Some(easing) => easing.ease(t),
+            None => t,
+        }
+    }
+}
+
+/// Advances each blend and moves its camera's targets to the blended state
+pub(crate) fn animate_blends(
+    clocks: Clocks,
+    mut blends: Query<(&mut PanOrbitBlend, &mut PanOrbitCamera)>,
+) {
+    for (mut blend, mut pan_orbit) in blends.iter_mut() {
+        if !blend.playing {
+            continue;
+        }
+        blend.elapsed += clocks.animation_delta_seconds(&pan_orbit);
+        if blend.elapsed >= blend.duration {
+            blend.elapsed = blend.duration;
+            blend.playing = false;
+        }
+        pan_orbit.blend_between(blend.from, blend.to, blend.t());
+    }
+}
+
+/// Event that hands control from one `PanOrbitCamera` to another, e.g. when switching from an
+/// overview rig to a detail rig in an editor. The `to` camera starts exactly where the `from`
+/// camera currently is, and then blends to its own target values over `duration` seconds, using
+/// a `PanOrbitBlend`, so the switch has no visible jump. The `from` camera is deactivated, the
+/// `to` camera is activated, and if the `from` camera was the active one in `ActiveCameraData`,
+/// the `to` camera becomes the active one instead.
+/// Both cameras should render to the same target, and the `to` camera keeps all its own settings.
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq)]
+pub struct PanOrbitHandoff {
+    /// The camera to take control from.
+    pub from: Entity,
+    /// The camera to hand control to.
+    pub to: Entity,
+    /// How long the `to` camera takes to blend to its own targets, in seconds.
+    pub duration: f32,
+    /// The easing function applied to the blend. If `None`, the blend is linear.
+    pub easing: Option<Easing>,
+}
+
+impl PanOrbitHandoff {
+    /// Create a handoff with a linear blend.
+    pub fn new(from: Entity, to: Entity, duration: f32) -> Self {
+        PanOrbitHandoff {
+            from,
+            to,
+            duration,
+            easing: None,
+        }
+    }
+
+    /// Set the easing function. Takes either an `Easing` or a `bevy_easings::EaseFunction`.
+    pub fn with_easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = Some(easing.into());
+        self
+    }
+}
+
+/// Moves the cameras of each handoff to the same place, switches which one is active, and starts
+/// the blend to the new camera's targets
+pub(crate) fn hand_off_cameras(
+    mut commands: Commands,
+    mut handoff_events: EventReader<PanOrbitHandoff>,
+    mut active_cam: ResMut<ActiveCameraData>,
+    mut cameras: Query<(&mut Camera, &mut PanOrbitCamera)>,
+) {
+    for handoff in handoff_events.read() {
+        let Ok([(mut from_camera, from), (mut to_camera, mut to)]) =
+            cameras.get_many_mut([handoff.from, handoff.to])
+        else {
+            warn!(
+                "Can't hand off from {:?} to {:?}, since they aren't two different cameras with \
+                 PanOrbitCamera",
+                handoff.from, handoff.to
+            );
+            continue;
+        };
+        let start = from.orbit_state();
+        let end = to.target_orbit_state();
+        to.set_immediate(start.alpha, start.beta, start.radius, start.focus);
+        from_camera.is_active = false;
+        to_camera.is_active = true;
+        let mut blend = PanOrbitBlend::new(start, end, handoff.duration);
+        blend.easing = handoff.easing;
+        commands.entity(handoff.to).insert(blend);
+        if !active_cam.manual && active_cam.entity == Some(handoff.from) {
+            active_cam.entity = Some(handoff.to);
+        }
+    }
+}
+
+#[cfg(test)]
+mod hand_off_cameras_tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn starts_where_the_old_camera_is() {
+        let mut world = World::new();
+        world.init_resource::<Events<PanOrbitHandoff>>();
+        let from = world
+            .spawn((
+                Camera::default(),
+                PanOrbitCamera::from_yaw_pitch_radius(1.0, 0.5, 4.0, Vec3::X),
+            ))
+            .id();
+        let to = world
+            .spawn((
+                Camera::default(),
+                PanOrbitCamera::from_yaw_pitch_radius(0.0, 0.2, 10.0, Vec3::ZERO),
+            ))
+            .id();
+        world.insert_resource(ActiveCameraData {
+            entity: Some(from),
+            ..default()
+        });
+        world.send_event(PanOrbitHandoff::new(from, to, 1.0));
+        world.run_system_once(hand_off_cameras);
+
+        assert_eq!(world.resource::<ActiveCameraData>().entity, Some(to));
+        assert!(!world.get::<Camera>(from).unwrap().is_active);
+        let pan_orbit = world.get::<PanOrbitCamera>(to).unwrap();
+        assert_eq!(
+            pan_orbit.orbit_state(),
+            OrbitState::new(Vec3::X, 1.0, 0.5, 4.0)
+        );
+        let blend = world.get::<PanOrbitBlend>(to).unwrap();
+        assert_eq!(blend.to, OrbitState::new(Vec3::ZERO, 0.0, 0.2, 10.0));
+    }
+}
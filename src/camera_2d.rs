@@ -0,0 +1,39 @@
+use crate::PanOrbitCamera;
+use bevy::prelude::*;
+
+/// Prepares cameras that have a standalone `OrthographicProjection`, e.g. from a
+/// `Camera2dBundle`, for use with `PanOrbitCamera`. The projection is moved into a `Projection`
+/// component, which is what the rest of the plugin works with, and the camera is locked to look
+/// straight down the negative Z axis, with rolling disabled, so it can only pan and zoom. The
+/// distance along Z from the focus to the camera is kept, so sprites stay within the
+/// projection's near and far planes.
+pub(crate) fn prepare_2d_cameras(
+    mut commands: Commands,
+    mut orbit_cameras: Query<
+        (
+            Entity,
+            &mut PanOrbitCamera,
+            &Transform,
+            &OrthographicProjection,
+        ),
+        Without<Projection>,
+    >,
+) {
+    for (entity, mut pan_orbit, transform, projection) in orbit_cameras.iter_mut() {
+        commands
+            .entity(entity)
+            .remove::<OrthographicProjection>()
+            .insert(Projection::Orthographic(projection.clone()));
+
+        let depth = transform.translation.z - pan_orbit.focus.z;
+        pan_orbit.alpha = Some(0.0);
+        pan_orbit.beta = Some(0.0);
+        pan_orbit.radius.get_or_insert(depth);
+        pan_orbit.roll = 0.0;
+        pan_orbit.target_roll = 0.0;
+        pan_orbit.bindings.button_roll = None;
+        pan_orbit.orbit.lock_alpha = true;
+        pan_orbit.orbit.lock_beta = true;
+        pan_orbit.initialized = false;
+    }
+}
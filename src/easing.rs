@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy_easings::EaseFunction;
+use interpolation::Ease;
+
+/// An easing function for `PanOrbitKeyframe`, `PanOrbitBlend`, and `PanOrbitHandoff`. These are
+/// the same as `bevy_easings::EaseFunction`, which converts to and from this, but can be edited in
+/// inspectors and saved in scenes.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Easing {
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+    QuinticIn,
+    QuinticOut,
+    QuinticInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    /// Apply the easing function to `t`, which goes from `0.0` to `1.0`.
+    pub fn ease(self, t: f32) -> f32 {
+        t.calc(self.into())
+    }
+}
+
+impl From<EaseFunction> for Easing {
+    fn from(easing: EaseFunction) -> Self {
+        match easing {
+            EaseFunction::QuadraticIn => Easing::QuadraticIn,
+            EaseFunction::QuadraticOut => Easing::QuadraticOut,
+            EaseFunction::QuadraticInOut => Easing::QuadraticInOut,
+            EaseFunction::CubicIn => Easing::CubicIn,
+            EaseFunction::CubicOut => Easing::CubicOut,
+            EaseFunction::CubicInOut => Easing::CubicInOut,
+            EaseFunction::QuarticIn => Easing::QuarticIn,
+            EaseFunction::QuarticOut => Easing::QuarticOut,
+            EaseFunction::QuarticInOut => Easing::QuarticInOut,
+            EaseFunction::QuinticIn => Easing::QuinticIn,
+            EaseFunction::QuinticOut => Easing::QuinticOut,
+            EaseFunction::QuinticInOut => Easing::QuinticInOut,
+            EaseFunction::SineIn => Easing::SineIn,
+            EaseFunction::SineOut => Easing::SineOut,
+            EaseFunction::SineInOut => Easing::SineInOut,
+            EaseFunction::CircularIn => Easing::CircularIn,
+            EaseFunction::CircularOut => Easing::CircularOut,
+            EaseFunction::CircularInOut => Easing::CircularInOut,
+            EaseFunction::ExponentialIn => Easing::ExponentialIn,
+            EaseFunction::ExponentialOut => Easing::ExponentialOut,
+            EaseFunction::ExponentialInOut => Easing::ExponentialInOut,
+            EaseFunction::ElasticIn => Easing::ElasticIn,
+            EaseFunction::ElasticOut => Easing::ElasticOut,
+            EaseFunction::ElasticInOut => Easing::ElasticInOut,
+            EaseFunction::BackIn => Easing::BackIn,
+            EaseFunction::BackOut => Easing::BackOut,
+            EaseFunction::BackInOut => Easing::BackInOut,
+            EaseFunction::BounceIn => Easing::BounceIn,
+            EaseFunction::BounceOut => Easing::BounceOut,
+            EaseFunction::BounceInOut => Easing::BounceInOut,
+        }
+    }
+}
+
+impl From<Easing> for EaseFunction {
+    fn from(easing: Easing) -> Self {
+        match easing {
+            Easing::QuadraticIn => EaseFunction::QuadraticIn,
+            Easing::QuadraticOut => EaseFunction::QuadraticOut,
+            Easing::QuadraticInOut => EaseFunction::QuadraticInOut,
+            Easing::CubicIn => EaseFunction::CubicIn,
+            Easing::CubicOut => EaseFunction::CubicOut,
+            Easing::CubicInOut => EaseFunction::CubicInOut,
+            Easing::QuarticIn => EaseFunction::QuarticIn,
+            Easing::QuarticOut => EaseFunction::QuarticOut,
+            Easing::QuarticInOut => EaseFunction::QuarticInOut,
+            Easing::QuinticIn => EaseFunction::QuinticIn,
+            Easing::QuinticOut => EaseFunction::QuinticOut,
+            Easing::QuinticInOut => EaseFunction::QuinticInOut,
+            Easing::SineIn => EaseFunction::SineIn,
+            Easing::SineOut => EaseFunction::SineOut,
+            Easing::SineInOut => EaseFunction::SineInOut,
+            Easing::CircularIn => EaseFunction::CircularIn,
+            Easing::CircularOut => EaseFunction::CircularOut,
+            Easing::CircularInOut => EaseFunction::CircularInOut,
+            Easing::ExponentialIn => EaseFunction::ExponentialIn,
+            Easing::ExponentialOut => EaseFunction::ExponentialOut,
+            Easing::ExponentialInOut => EaseFunction::ExponentialInOut,
+            Easing::ElasticIn => EaseFunction::ElasticIn,
+            Easing::ElasticOut => EaseFunction::ElasticOut,
+            Easing::ElasticInOut => EaseFunction::ElasticInOut,
+            Easing::BackIn => EaseFunction::BackIn,
+            Easing::BackOut => EaseFunction::BackOut,
+            Easing::BackInOut => EaseFunction::BackInOut,
+            Easing::BounceIn => EaseFunction::BounceIn,
+            Easing::BounceOut => EaseFunction::BounceOut,
+            Easing::BounceInOut => EaseFunction::BounceInOut,
+        }
+    }
+}
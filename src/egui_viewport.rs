@@ -0,0 +1,241 @@
+use crate::{active_drag_pressed, util, ActiveCameraData, PanOrbitCamera};
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::render::camera::{RenderTarget, Viewport};
+use bevy::window::{PrimaryWindow, WindowRef};
+use bevy_egui::EguiContexts;
+
+/// Add this to an entity with `PanOrbitCamera` that renders to an image which is shown inside an
+/// egui widget, i.e. a 3D viewport in an egui panel. The plugin then activates the camera when
+/// the user interacts with the widget, and doesn't ignore input over it like it does for other
+/// egui areas, so you don't need to manage `ActiveCameraData` yourself.
+/// Update `rect` every frame with the rect of the widget the image is shown in.
+/// Requires the `bevy_egui` feature.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_egui::{egui, EguiContexts};
+/// # use bevy_panorbit_camera::EguiViewportCamera;
+/// fn viewport_ui(
+///     mut contexts: EguiContexts,
+///     mut viewports: Query<&mut EguiViewportCamera>,
+///     image: Res<ViewportImage>,
+/// ) {
+///     let texture_id = contexts.image_id(&image.0).unwrap();
+///     egui::CentralPanel::default().show(contexts.ctx_mut(), |ui| {
+///         let response = ui.image(egui::load::SizedTexture::new(texture_id, [512.0, 512.0]));
+///         let rect = response.rect;
+///         viewports.single_mut().rect = Rect::new(rect.min.x, rect.min.y, rect.max.x, rect.max.y);
+///     });
+/// }
+/// # #[derive(Resource)]
+/// # struct ViewportImage(Handle<Image>);
+/// ```
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct EguiViewportCamera {
+    /// The area of the window that the camera's image is shown in, in logical pixels, with the
+    /// origin at the top left of the window. This is the same as the `rect` of the egui
+    /// `Response`, as long as the egui scale factor is `1.0`. Defaults to an empty rect.
+    pub rect: Rect,
+    /// The window that contains the egui widget. If `None`, the primary window is used.
+    /// Defaults to `None`.
+    pub window: Option<Entity>,
+}
+
+impl EguiViewportCamera {
+    /// Whether the cursor is currently inside `rect`
+    pub(crate) fn contains_cursor(
+        &self,
+        primary_windows: &Query<&Window, With<PrimaryWindow>>,
+        other_windows: &Query<&Window, Without<PrimaryWindow>>,
+    ) -> bool {
+        self.window(primary_windows, other_windows)
+            .and_then(|window| window.cursor_position())
+            .is_some_and(|cursor_pos| self.rect.contains(cursor_pos))
+    }
+
+    fn window<'a>(
+        &self,
+        primary_windows: &'a Query<&Window, With<PrimaryWindow>>,
+        other_windows: &'a Query<&Window, Without<PrimaryWindow>>,
+    ) -> Option<&'a Window> {
+        match self.window {
+            None => primary_windows.get_single().ok(),
+            Some(entity) => other_windows
+                .get(entity)
+                .or_else(|_| primary_windows.get(entity))
+                .ok(),
+        }
+    }
+}
+
+/// Finds out whether the pointer is over egui areas that should block camera input
+#[derive(SystemParam)]
+pub(crate) struct EguiPointer<'w, 's> {
+    contexts: EguiContexts<'w, 's>,
+    windows: Query<'w, 's, (Entity, Has<PrimaryWindow>), With<Window>>,
+    viewports: Query<'w, 's, &'static EguiViewportCamera>,
+}
+
+impl EguiPointer<'_, '_> {
+    /// The windows where the pointer is over an egui area, and whether each is the primary
+    /// window
+    pub(crate) fn windows_with_pointer(&mut self) -> Vec<(Entity, bool)> {
+        let mut windows = Vec::new();
+        for (window, primary) in self.windows.iter() {
+            if self
+                .contexts
+                .try_ctx_for_window_mut(window)
+                .is_some_and(|ctx| ctx.is_pointer_over_area())
+            {
+                windows.push((window, primary));
+            }
+        }
+        windows
+    }
+
+    /// Whether egui is in the way of input for the camera, given the windows from
+    /// `windows_with_pointer`. Only egui in the window the camera renders to blocks it, so input
+    /// in one window isn't blocked by egui in another. Cameras that render to images may be shown
+    /// in any window, so egui in any window blocks them.
+    pub(crate) fn blocks_camera(
+        &self,
+        egui_windows: &[(Entity, bool)],
+        entity: Entity,
+        camera: &Camera,
+        primary_windows: &Query<&Window, With<PrimaryWindow>>,
+        other_windows: &Query<&Window, Without<PrimaryWindow>>,
+    ) -> bool {
+        let over_egui = match camera.target {
+            RenderTarget::Window(WindowRef::Primary) => {
+                egui_windows.iter().any(|(_, primary)| *primary)
+            }
+            RenderTarget::Window(WindowRef::Entity(target)) => {
+                egui_windows.iter().any(|(window, _)| *window == target)
+            }
+            _ => !egui_windows.is_empty(),
+        };
+        // Egui viewports are egui areas themselves, but input over them is meant for the camera
+        over_egui
+            && !self
+                .viewports
+                .get(entity)
+                .is_ok_and(|viewport| viewport.contains_cursor(primary_windows, other_windows))
+    }
+}
+
+/// Activates the `EguiViewportCamera` under the cursor when the user starts interacting with it
+pub(crate) fn egui_viewport_data(
+    mut active_cam: ResMut<ActiveCameraData>,
+    mouse_input: Res<Input<MouseButton>>,
+    key_input: Res<Input<KeyCode>>,
+    scroll_events: EventReader<MouseWheel>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    orbit_cameras: Query<(Entity, &PanOrbitCamera, &EguiViewportCamera)>,
+    all_cameras: Query<(Entity, &Camera, &PanOrbitCamera)>,
+) {
+    // Keep the active camera's sizes up to date, since the viewport and window can change size
+    // in the middle of a drag, e.g. when the window is moved to a monitor with a different DPI
+    if let Some((viewport, window)) = active_cam
+        .entity
+        .and_then(|entity| orbit_cameras.get(entity).ok())
+        .and_then(|(_, _, viewport)| {
+            Some((viewport, viewport.window(&primary_windows, &other_windows)?))
+        })
+    {
+        let viewport_size = Some(viewport.rect.size());
+        let window_size = Some(Vec2::new(window.width(), window.height()));
+        if active_cam.viewport_size != viewport_size || active_cam.window_size != window_size {
+            active_cam.viewport_size = viewport_size;
+            active_cam.window_size = window_size;
+        }
+    }
+
+    // Don't hand an ongoing drag off to another camera
+    if active_cam.dragging && active_drag_pressed(&active_cam, &mouse_input, &all_cameras) {
+        return;
+    }
+    for (entity, pan_orbit, viewport) in orbit_cameras.iter() {
+        let input_just_activated = util::orbit_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || util::pan_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || util::zoom_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || util::roll_just_pressed(pan_orbit, &mouse_input)
+            || !scroll_events.is_empty();
+        if !input_just_activated || !viewport.contains_cursor(&primary_windows, &other_windows) {
+            continue;
+        }
+        let Some(window) = viewport.window(&primary_windows, &other_windows) else {
+            continue;
+        };
+        active_cam.set_if_neq(ActiveCameraData {
+            entity: Some(entity),
+            viewport_size: Some(viewport.rect.size()),
+            window_size: Some(Vec2::new(window.width(), window.height())),
+            manual: false,
+            dragging: false,
+        });
+        break;
+    }
+}
+
+/// Add this to an entity with `PanOrbitCamera` that renders to a window with egui side, top, or
+/// bottom panels. Every frame, the camera's viewport is set to the area of the window that isn't
+/// covered by panels (egui's available rect), so the 3D view is centered in the remaining space,
+/// and cursor and input calculations are relative to the visible part of the view rather than
+/// the whole window.
+/// Your egui panels must be shown before `PanOrbitCameraSystemSet` runs, otherwise the viewport
+/// lags one frame behind.
+/// Requires the `bevy_egui` feature.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct EguiFitViewport;
+
+/// Sets the viewport of each `EguiFitViewport` camera to egui's available rect
+pub(crate) fn fit_viewports_to_egui_panels(
+    mut contexts: EguiContexts,
+    primary_windows: Query<Entity, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Camera, With<EguiFitViewport>>,
+) {
+    for mut camera in cameras.iter_mut() {
+        let window = match camera.target {
+            RenderTarget::Window(WindowRef::Primary) => primary_windows.get_single().ok(),
+            RenderTarget::Window(WindowRef::Entity(entity)) => Some(entity),
+            _ => None,
+        };
+        let Some(window) = window else {
+            continue;
+        };
+        // The window's egui context doesn't exist yet, or the window was just closed
+        let Some(ctx) = contexts.try_ctx_for_window_mut(window) else {
+            continue;
+        };
+        let rect = ctx.available_rect();
+        let pixels_per_point = ctx.pixels_per_point();
+        let position = Vec2::new(rect.min.x, rect.min.y) * pixels_per_point;
+        let size = Vec2::new(rect.max.x - rect.min.x, rect.max.y - rect.min.y) * pixels_per_point;
+        let (position, size) = (position.as_uvec2(), size.as_uvec2());
+        if size.x == 0 || size.y == 0 {
+            continue;
+        }
+
+        // Avoid triggering change detection if nothing changed
+        let current = camera
+            .viewport
+            .as_ref()
+            .map(|viewport| (viewport.physical_position, viewport.physical_size));
+        if current != Some((position, size)) {
+            let depth = camera
+                .viewport
+                .as_ref()
+                .map_or(0.0..1.0, |viewport| viewport.depth.clone());
+            camera.viewport = Some(Viewport {
+                physical_position: position,
+                physical_size: size,
+                depth,
+            });
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    input, util, ActiveCameraData, Clocks, OrbitState, PanOrbitCamera, SCROLL_LINE_PIXELS,
+};
+use bevy::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+/// Add this to an entity with `PanOrbitCamera` to be able to switch it between orbiting and an
+/// FPS-style fly mode, like the cameras in most editors. While flying, the movement keys move the
+/// camera relative to the direction it's facing, moving the mouse turns it in place, and the
+/// scroll wheel changes the speed. The `PanOrbitCamera`'s own controls are disabled while flying.
+/// When switching back to orbiting, the camera smoothly returns to where it was before flying,
+/// unless `restore_on_exit` is `false`, in which case it orbits around a point in front of
+/// wherever it ended up.
+/// Flying only responds to input while the camera is the active camera (see `ActiveCameraData`),
+/// i.e. after the user has interacted with it.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct FlyMode {
+    /// Whether the camera is flying. Toggled by `key_toggle`, or set it directly to switch modes
+    /// from code. Defaults to `false`.
+    pub active: bool,
+    /// Key that toggles between orbiting and flying. Defaults to `Some(KeyCode::F)`.
+    pub key_toggle: Option<KeyCode>,
+    /// Key that flies forward. Defaults to `Some(KeyCode::W)`.
+    pub key_forward: Option<KeyCode>,
+    /// Key that flies backward. Defaults to `Some(KeyCode::S)`.
+    pub key_back: Option<KeyCode>,
+    /// Key that flies left. Defaults to `Some(KeyCode::A)`.
+    pub key_left: Option<KeyCode>,
+    /// Key that flies right. Defaults to `Some(KeyCode::D)`.
+    pub key_right: Option<KeyCode>,
+    /// Key that flies straight up, regardless of where the camera is facing. Defaults to
+    /// `Some(KeyCode::E)`.
+    pub key_up: Option<KeyCode>,
+    /// Key that flies straight down, regardless of where the camera is facing. Defaults to
+    /// `Some(KeyCode::Q)`.
+    pub key_down: Option<KeyCode>,
+    /// Mouse button that must be held to look around. If `None`, moving the mouse always turns
+    /// the camera, which works best with a grabbed cursor. Defaults to
+    /// `Some(MouseButton::Right)`.
+    pub button_look: Option<MouseButton>,
+    /// How far the camera turns per pixel of mouse motion, in radians. Defaults to `0.003`.
+    pub look_sensitivity: f32,
+    /// How fast the camera flies, in world units per second. Defaults to `5.0`.
+    pub speed: f32,
+    /// How much each line of scrolling multiplies `speed` by, so scrolling up flies faster and
+    /// scrolling down flies slower. Defaults to `1.1`.
+    pub speed_scroll_factor: f32,
+    /// Whether to return to the orbit state from before flying when switching back to orbiting.
+    /// Defaults to `true`.
+    pub restore_on_exit: bool,
+    /// The target orbit state and `enabled` flag from before flying, if currently flying.
+    #[reflect(ignore)]
+    saved: Option<(OrbitState, bool)>,
+}
+
+impl Default for FlyMode {
+    fn default() -> Self {
+        FlyMode {
+            active: false,
+            key_toggle: Some(KeyCode::F),
+            key_forward: Some(KeyCode::W),
+            key_back: Some(KeyCode::S),
+            key_left: Some(KeyCode::A),
+            key_right: Some(KeyCode::D),
+            key_up: Some(KeyCode::E),
+            key_down: Some(KeyCode::Q),
+            button_look: Some(MouseButton::Right),
+            look_sensitivity: 0.003,
+            speed: 5.0,
+            speed_scroll_factor: 1.1,
+            restore_on_exit: true,
+            saved: None,
+        }
+    }
+}
+
+impl FlyMode {
+    /// Whether the camera is currently flying, i.e. the switch to fly mode has been applied.
+    /// Unlike `active`, this only changes once the fly mode system has run.
+    pub fn is_flying(&self) -> bool {
+        self.saved.is_some()
+    }
+}
+
+/// The focus and rotation that keep the camera at its current position when it turns to face
+/// the given alpha and beta. Flying is implemented by moving the focus along with the camera,
+/// so the `PanOrbitCamera` keeps writing the transform as usual.
+fn look_in_place(pan_orbit: &PanOrbitCamera, alpha: f32, beta: f32) -> (Vec3, Quat) {
+    let position = pan_orbit.transform().translation;
+    let mut relative = Transform::default();
+    util::update_orbit_transform(
+        alpha,
+        beta,
+        pan_orbit.roll,
+        pan_orbit.radius.unwrap_or(pan_orbit.target_radius),
+        Vec3::ZERO,
+        pan_orbit.focus_offset,
+        pan_orbit.orientation_offset,
+        &mut relative,
+    );
+    (position - relative.translation, relative.rotation)
+}
+
+/// Switches cameras between orbiting and flying, and applies the fly controls
+pub(crate) fn fly_mode_controller(
+    clocks: Clocks,
+    active_cam: Res<ActiveCameraData>,
+    key_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    input_buffer: Res<input::InputBuffer>,
+    mut orbit_cameras: Query<(Entity, &mut FlyMode, &mut PanOrbitCamera)>,
+) {
+    for (entity, mut fly, mut pan_orbit) in orbit_cameras.iter_mut() {
+        let is_active = active_cam.entity == Some(entity);
+        if is_active
+            && fly
+                .key_toggle
+                .is_some_and(|key| key_input.just_pressed(key))
+        {
+            fly.active = !fly.active;
+        }
+
+        if fly.active && fly.saved.is_none() {
+            // Stop any ongoing motion, so the camera starts flying from where it is now
+            let state = pan_orbit.orbit_state();
+            fly.saved = Some((pan_orbit.target_orbit_state(), pan_orbit.enabled));
+            pan_orbit.enabled = false;
+            pan_orbit.set_immediate(state.alpha, state.beta, state.radius, state.focus);
+        } else if !fly.active {
+            if let Some((state, enabled)) = fly.saved.take() {
+                pan_orbit.enabled = enabled;
+                if fly.restore_on_exit {
+                    pan_orbit.set_target_orbit_state(state);
+                }
+            }
+            continue;
+        }
+
+        if !is_active {
+            continue;
+        }
+
+        let scroll = input_buffer.scroll_line.y + input_buffer.scroll_pixel.y / SCROLL_LINE_PIXELS;
+        if scroll != 0.0 {
+            fly.speed *= fly.speed_scroll_factor.powf(scroll);
+        }
+
+        let state = pan_orbit.orbit_state();
+        let mut alpha = state.alpha;
+        let mut beta = state.beta;
+        let looking = fly
+            .button_look
+            .is_none_or(|button| mouse_input.pressed(button));
+        if looking && input_buffer.mouse_delta != Vec2::ZERO {
+            let delta = input_buffer.mouse_delta * fly.look_sensitivity;
+            alpha -= delta.x;
+            // Stop just short of straight up or down, where the yaw would flip
+            beta = (beta + delta.y).clamp(-FRAC_PI_2 + 0.01, FRAC_PI_2 - 0.01);
+        }
+
+        let pressed = |key: Option<KeyCode>| key.is_some_and(|key| key_input.pressed(key));
+        // Camera space direction, so negative Z is forward
+        let mut direction = Vec3::ZERO;
+        if pressed(fly.key_forward) {
+            direction.z -= 1.0;
+        }
+        if pressed(fly.key_back) {
+            direction.z += 1.0;
+        }
+        if pressed(fly.key_left) {
+            direction.x -= 1.0;
+        }
+        if pressed(fly.key_right) {
+            direction.x += 1.0;
+        }
+        let mut vertical = 0.0;
+        if pressed(fly.key_up) {
+            vertical += 1.0;
+        }
+        if pressed(fly.key_down) {
+            vertical -= 1.0;
+        }
+
+        if alpha == state.alpha && beta == state.beta && direction == Vec3::ZERO && vertical == 0.0
+        {
+            continue;
+        }
+
+        let (mut focus, rotation) = look_in_place(&pan_orbit, alpha, beta);
+        let movement = rotation * direction.normalize_or_zero() + Vec3::Y * vertical;
+        focus += movement * fly.speed * clocks.delta_seconds(pan_orbit.time_source);
+        pan_orbit.set_immediate(alpha, beta, state.radius, focus);
+    }
+}
+
+#[cfg(test)]
+mod look_in_place_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn turning_keeps_the_camera_position() {
+        let mut pan_orbit = PanOrbitCamera::from_yaw_pitch_radius(0.3, 0.2, 7.0, Vec3::X);
+        let before = pan_orbit.transform().translation;
+        let (focus, rotation) = look_in_place(&pan_orbit, 1.2, -0.4);
+        pan_orbit.set_immediate(1.2, -0.4, 7.0, focus);
+        let after = pan_orbit.transform();
+        assert!(approx_eq!(
+            f32,
+            before.distance(after.translation),
+            0.0,
+            epsilon = 0.0001
+        ));
+        assert!(after.rotation.angle_between(rotation) < 0.0001);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::PanOrbitCamera;
+use bevy::prelude::*;
+
+/// Add this to an entity with `PanOrbitCamera` to keep the camera's focus at ground level, e.g.
+/// for an RTS camera over heightfield terrain. The function is given the X/Z position of the
+/// target focus and returns the ground height there, which the target focus's Y position is set
+/// to every frame, after panning and focus limits are applied.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitFocusHeight};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3dBundle::default(),
+///         PanOrbitCamera::default(),
+///         PanOrbitFocusHeight::new(|pos| (pos.x * 0.1).sin() * (pos.y * 0.1).cos() * 5.0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect)]
+#[reflect(Component, Default, from_reflect = false)]
+pub struct PanOrbitFocusHeight(#[reflect(ignore)] pub Box<dyn Fn(Vec2) -> f32 + Send + Sync>);
+
+impl Default for PanOrbitFocusHeight {
+    /// Flat ground at a height of `0.0`.
+    fn default() -> Self {
+        PanOrbitFocusHeight::new(|_| 0.0)
+    }
+}
+
+impl PanOrbitFocusHeight {
+    /// Create a focus height provider from the given function.
+    pub fn new(height_at: impl Fn(Vec2) -> f32 + Send + Sync + 'static) -> Self {
+        PanOrbitFocusHeight(Box::new(height_at))
+    }
+}
+
+/// Moves the target focus of each camera to the ground height under it
+pub(crate) fn apply_focus_heights(
+    mut orbit_cameras: Query<(&PanOrbitFocusHeight, &mut PanOrbitCamera)>,
+) {
+    for (focus_height, mut pan_orbit) in orbit_cameras.iter_mut() {
+        let height = (focus_height.0)(pan_orbit.target_focus.xz());
+        // Avoid triggering change detection while the camera is idle
+        if pan_orbit.target_focus.y != height {
+            pan_orbit.target_focus.y = height;
+        }
+    }
+}
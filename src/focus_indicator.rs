@@ -0,0 +1,82 @@
+use crate::PanOrbitCamera;
+use bevy::prelude::*;
+
+/// Add this to an entity with `PanOrbitCamera` to show a marker at the focus point while the
+/// camera is moving, similar to the orbit widget in Blender or Sketchfab. The marker fades in
+/// when the camera starts moving, and fades out once it comes to rest.
+/// The marker is drawn with gizmos, so it is visible to all cameras that render gizmos.
+/// Requires the `focus_indicator` feature.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitFocusIndicator {
+    /// The color of the marker. The alpha component is used as the maximum opacity.
+    /// Defaults to semi-transparent white.
+    pub color: Color,
+    /// The radius of the marker, as a fraction of the camera's `radius` (or `scale`, for
+    /// orthographic cameras), so the marker stays the same size on screen when zooming.
+    /// Defaults to `0.02`.
+    pub size: f32,
+    /// How long it takes to fade in, in seconds. Defaults to `0.1`.
+    pub fade_in_duration: f32,
+    /// How long it takes to fade out, in seconds. Defaults to `0.5`.
+    pub fade_out_duration: f32,
+    /// The current opacity, from `0.0` to `1.0`. Updated automatically.
+    pub opacity: f32,
+}
+
+impl Default for PanOrbitFocusIndicator {
+    fn default() -> Self {
+        PanOrbitFocusIndicator {
+            color: Color::rgba(1.0, 1.0, 1.0, 0.6),
+            size: 0.02,
+            fade_in_duration: 0.1,
+            fade_out_duration: 0.5,
+            opacity: 0.0,
+        }
+    }
+}
+
+/// Fades focus indicators in or out depending on whether their camera moved this frame, and
+/// draws them
+pub(crate) fn draw_focus_indicators(
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut cameras: Query<(
+        &PanOrbitCamera,
+        Ref<Transform>,
+        &Projection,
+        &mut PanOrbitFocusIndicator,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (pan_orbit, transform, projection, mut indicator) in cameras.iter_mut() {
+        // The transform is only touched when the camera actually moves
+        if transform.is_changed() {
+            indicator.opacity += dt / indicator.fade_in_duration.max(f32::EPSILON);
+        } else {
+            indicator.opacity -= dt / indicator.fade_out_duration.max(f32::EPSILON);
+        }
+        indicator.opacity = indicator.opacity.clamp(0.0, 1.0);
+
+        if indicator.opacity <= 0.0 {
+            continue;
+        }
+
+        let zoom = match projection {
+            Projection::Perspective(_) => pan_orbit.radius,
+            Projection::Orthographic(_) => pan_orbit.scale,
+        };
+        let Some(zoom) = zoom else {
+            continue;
+        };
+        let color = indicator
+            .color
+            .with_a(indicator.color.a() * indicator.opacity);
+        gizmos.sphere(
+            pan_orbit.focus,
+            Quat::IDENTITY,
+            zoom * indicator.size,
+            color,
+        );
+    }
+}
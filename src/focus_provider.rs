@@ -0,0 +1,92 @@
+use crate::{ActiveCameraData, PanOrbitCamera, PanOrbitCameraSubSet};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Implement this for a resource that knows where the camera should focus, e.g. the centroid of
+/// the current selection in an editor. Register it with `FocusProviderPlugin`, after which the
+/// active camera can be re-centered on that point by pressing `Bindings::key_recenter`, or
+/// by sending a `RecenterFocus` event.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{FocusProvider, FocusProviderPlugin, PanOrbitCameraPlugin};
+/// #[derive(Resource, Default)]
+/// struct Selection {
+///     centroid: Option<Vec3>,
+/// }
+///
+/// impl FocusProvider for Selection {
+///     fn focus(&self) -> Option<Vec3> {
+///         self.centroid
+///     }
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(PanOrbitCameraPlugin)
+///         .init_resource::<Selection>()
+///         .add_plugins(FocusProviderPlugin::<Selection>::default())
+///         .run();
+/// }
+/// ```
+pub trait FocusProvider: Resource {
+    /// The point to focus on, or `None` if there is nothing to focus on.
+    fn focus(&self) -> Option<Vec3>;
+}
+
+/// Event that re-centers the active `PanOrbitCamera` (or all of them, if there is no active
+/// camera) on the point given by the registered `FocusProvider`.
+#[derive(Event, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+pub struct RecenterFocus;
+
+/// Adds support for re-centering cameras on the point given by the `FocusProvider` resource `T`.
+/// Requires `PanOrbitCameraPlugin`.
+pub struct FocusProviderPlugin<T: FocusProvider>(PhantomData<T>);
+
+impl<T: FocusProvider> Default for FocusProviderPlugin<T> {
+    fn default() -> Self {
+        FocusProviderPlugin(PhantomData)
+    }
+}
+
+impl<T: FocusProvider> Plugin for FocusProviderPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RecenterFocus>()
+            .register_type::<RecenterFocus>()
+            .add_systems(
+                Update,
+                recenter_focus::<T>
+                    .in_set(PanOrbitCameraSubSet::ProcessMotion)
+                    .before(crate::pan_orbit_camera),
+            );
+    }
+}
+
+fn recenter_focus<T: FocusProvider>(
+    provider: Option<Res<T>>,
+    active_cam: Res<ActiveCameraData>,
+    key_input: Res<Input<KeyCode>>,
+    mut recenter_events: EventReader<RecenterFocus>,
+    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera)>,
+) {
+    let recenter_requested = recenter_events.read().count() > 0;
+    let Some(focus) = provider.and_then(|provider| provider.focus()) else {
+        return;
+    };
+
+    for (entity, mut pan_orbit) in orbit_cameras.iter_mut() {
+        if active_cam.entity.is_some_and(|active| active != entity) {
+            continue;
+        }
+        let key_pressed = pan_orbit.enabled
+            && pan_orbit
+                .bindings
+                .key_recenter
+                .is_some_and(|key| key_input.just_pressed(key));
+        if recenter_requested || key_pressed {
+            // Only the target is set, so the camera smoothly animates to the new focus
+            pan_orbit.target_focus = focus;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Clocks, PanOrbitCamera};
+use bevy::prelude::*;
+
+/// Add this to an entity with `PanOrbitCamera` to make the camera's focus follow another entity.
+/// Optionally, the focus can lead the target based on its velocity, which is useful for chase
+/// cameras in racing or flying games, so the camera looks where the target is going rather than
+/// where it is.
+/// The camera can still be orbited and zoomed as usual, but panning will be overridden.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct PanOrbitFollow {
+    /// The entity to follow. It must have a `GlobalTransform`.
+    pub target: Entity,
+    /// An offset from the target's position to focus on, in world space. Defaults to
+    /// `Vec3::ZERO`.
+    pub offset: Vec3,
+    /// How far ahead to predict the target's position, in seconds. The focus is placed where the
+    /// target will be after this amount of time if it keeps moving at its current velocity.
+    /// Defaults to `0.0` (no prediction).
+    pub prediction_time: f32,
+    /// The estimated velocity of the target, in units per second. Updated automatically.
+    pub velocity: Vec3,
+    /// The target's position in the previous frame. Updated automatically.
+    pub last_position: Option<Vec3>,
+}
+
+impl PanOrbitFollow {
+    /// Follow the given entity, without any offset or prediction.
+    pub fn new(target: Entity) -> Self {
+        PanOrbitFollow {
+            target,
+            offset: Vec3::ZERO,
+            prediction_time: 0.0,
+            velocity: Vec3::ZERO,
+            last_position: None,
+        }
+    }
+}
+
+// Reflection needs a placeholder value to insert the component with, before the reflected
+// values are applied, like Bevy's own `Parent`
+impl FromWorld for PanOrbitFollow {
+    fn from_world(_world: &mut World) -> Self {
+        PanOrbitFollow::new(Entity::PLACEHOLDER)
+    }
+}
+
+/// Moves the target focus of following cameras to the (predicted) position of their target
+pub(crate) fn follow_targets(
+    clocks: Clocks,
+    targets: Query<&GlobalTransform>,
+    mut followers: Query<(&mut PanOrbitFollow, &mut PanOrbitCamera)>,
+) {
+    for (mut follow, mut pan_orbit) in followers.iter_mut() {
+        let Ok(target_transform) = targets.get(follow.target) else {
+            continue;
+        };
+        let position = target_transform.translation();
+        let dt = clocks.delta_seconds(pan_orbit.time_source);
+        if let Some(last_position) = follow.last_position {
+            if dt > 0.0 {
+                follow.velocity = (position - last_position) / dt;
+            }
+        }
+        follow.last_position = Some(position);
+
+        pan_orbit.target_focus =
+            position + follow.offset + follow.velocity * follow.prediction_time;
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{util, ActiveCameraData, PanOrbitCamera};
+use bevy::prelude::*;
+
+/// Add this to several entities with `PanOrbitCamera` to make them orbit and zoom together, e.g.
+/// for four-way CAD views or a mirror view. When the active camera of a group is moved, the same
+/// change in alpha and beta is applied to every other camera in the group, and their radius and
+/// scale are multiplied by the same factor. Each camera keeps its own offset from the others,
+/// so cameras can look at the scene from different angles.
+/// The number identifies the group. Cameras with different numbers are independent.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct PanOrbitGroup(pub u32);
+
+/// The target values of a group's active camera at the end of the last frame
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct LeaderTargets {
+    entity: Entity,
+    alpha: f32,
+    beta: f32,
+    radius: f32,
+    scale: f32,
+    normalize_alpha: bool,
+}
+
+impl LeaderTargets {
+    fn new(entity: Entity, pan_orbit: &PanOrbitCamera) -> Self {
+        LeaderTargets {
+            entity,
+            alpha: pan_orbit.target_alpha,
+            beta: pan_orbit.target_beta,
+            radius: pan_orbit.target_radius,
+            scale: pan_orbit.target_scale,
+            normalize_alpha: pan_orbit.orbit.normalize_alpha,
+        }
+    }
+}
+
+/// Propagates changes to the active camera's targets to the other cameras in its group
+pub(crate) fn sync_groups(
+    active_cam: Res<ActiveCameraData>,
+    mut last_leader: Local<Option<LeaderTargets>>,
+    mut orbit_cameras: Query<(Entity, &PanOrbitGroup, &mut PanOrbitCamera)>,
+) {
+    let Some((leader, group, leader_targets)) = active_cam
+        .entity
+        .and_then(|entity| orbit_cameras.get(entity).ok())
+        .map(|(entity, group, pan_orbit)| (entity, *group, LeaderTargets::new(entity, pan_orbit)))
+    else {
+        *last_leader = None;
+        return;
+    };
+
+    if let Some(last) = *last_leader {
+        if last.entity == leader && last != leader_targets {
+            let ratio = |new: f32, old: f32| if old != 0.0 { new / old } else { 1.0 };
+            let mut alpha_delta = leader_targets.alpha - last.alpha;
+            if leader_targets.normalize_alpha {
+                // Ignore the leader's alpha being wrapped back into range
+                alpha_delta = util::shortest_angle_delta(last.alpha, leader_targets.alpha);
+            }
+            let beta_delta = leader_targets.beta - last.beta;
+            let radius_ratio = ratio(leader_targets.radius, last.radius);
+            let scale_ratio = ratio(leader_targets.scale, last.scale);
+            for (entity, member_group, mut pan_orbit) in orbit_cameras.iter_mut() {
+                if entity == leader || *member_group != group {
+                    continue;
+                }
+                pan_orbit.target_alpha += alpha_delta;
+                pan_orbit.target_beta += beta_delta;
+                pan_orbit.target_radius *= radius_ratio;
+                pan_orbit.target_scale *= scale_ratio;
+            }
+        }
+    }
+    *last_leader = Some(leader_targets);
+}
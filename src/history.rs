@@ -0,0 +1,216 @@
+use crate::{ActiveCameraData, OrbitState, PanOrbitCamera};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Add this to an entity with `PanOrbitCamera` to keep a history of the places the camera came
+/// to rest at, and navigate back and forward through it, e.g. to undo an accidental jump like in
+/// CAD software. A new entry is recorded each time the camera stops moving somewhere new, and
+/// navigating back and forward moves the camera smoothly to the recorded focus, alpha, beta, and
+/// radius.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitHistory};
+/// fn setup(mut commands: Commands) {
+///     let mut history = PanOrbitHistory::default();
+///     history.key_back = Some(KeyCode::Left);
+///     history.key_forward = Some(KeyCode::Right);
+///     commands.spawn((Camera3dBundle::default(), PanOrbitCamera::default(), history));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitHistory {
+    /// The maximum number of entries to keep. The oldest entries are dropped first.
+    /// Defaults to `50`.
+    pub capacity: usize,
+    /// Mouse button that navigates back, usually the "back" side button of the mouse, which is
+    /// `MouseButton::Other(..)` with a platform specific number. Defaults to `None`.
+    pub button_back: Option<MouseButton>,
+    /// Mouse button that navigates forward. Defaults to `None`.
+    pub button_forward: Option<MouseButton>,
+    /// Key that navigates back. Defaults to `None`.
+    pub key_back: Option<KeyCode>,
+    /// Key that navigates forward. Defaults to `None`.
+    pub key_forward: Option<KeyCode>,
+    #[reflect(ignore)]
+    entries: Vec<OrbitState>,
+    #[reflect(ignore)]
+    index: usize,
+    #[reflect(ignore)]
+    pending: bool,
+}
+
+impl Default for PanOrbitHistory {
+    fn default() -> Self {
+        PanOrbitHistory {
+            capacity: 50,
+            button_back: None,
+            button_forward: None,
+            key_back: None,
+            key_forward: None,
+            entries: Vec::new(),
+            index: 0,
+            pending: false,
+        }
+    }
+}
+
+impl PanOrbitHistory {
+    /// Move the camera to the previous entry. Returns `false` if there is no previous entry.
+    pub fn navigate_back(&mut self) -> bool {
+        if self.index == 0 || self.entries.is_empty() {
+            return false;
+        }
+        self.index -= 1;
+        self.pending = true;
+        true
+    }
+
+    /// Move the camera to the next entry, after navigating back. Returns `false` if there is no
+    /// next entry.
+    pub fn navigate_forward(&mut self) -> bool {
+        if self.index + 1 >= self.entries.len() {
+            return false;
+        }
+        self.index += 1;
+        self.pending = true;
+        true
+    }
+
+    /// Whether there is an entry to navigate back to.
+    pub fn can_navigate_back(&self) -> bool {
+        self.index > 0
+    }
+
+    /// Whether there is an entry to navigate forward to.
+    pub fn can_navigate_forward(&self) -> bool {
+        self.index + 1 < self.entries.len()
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.index = 0;
+        self.pending = false;
+    }
+
+    /// The entry the camera is currently at, or moving towards
+    fn current(&self) -> Option<&OrbitState> {
+        self.entries.get(self.index)
+    }
+
+    /// Adds a new entry after the current one, dropping any entries that could be navigated
+    /// forward to, unless it is the same as the current one
+    fn record(&mut self, state: OrbitState) {
+        if self
+            .current()
+            .is_some_and(|current| same_view(current, &state))
+        {
+            return;
+        }
+        self.entries.truncate(self.index + 1);
+        self.entries.push(state);
+        let excess = self.entries.len().saturating_sub(self.capacity.max(1));
+        self.entries.drain(..excess);
+        self.index = self.entries.len() - 1;
+    }
+}
+
+/// Whether two states show the same view, allowing for small differences, and for alpha being
+/// normalized to a different turn
+fn same_view(a: &OrbitState, b: &OrbitState) -> bool {
+    const EPSILON: f32 = 1e-3;
+    let alpha_difference = (a.alpha - b.alpha).rem_euclid(TAU);
+    a.focus.abs_diff_eq(b.focus, EPSILON)
+        && alpha_difference.min(TAU - alpha_difference) < EPSILON
+        && (a.beta - b.beta).abs() < EPSILON
+        && (a.radius - b.radius).abs() < EPSILON
+}
+
+/// Handles navigation input, moves cameras to the entry they navigated to, and records new
+/// entries once cameras come to rest
+pub(crate) fn update_history(
+    active_cam: Res<ActiveCameraData>,
+    mouse_input: Res<Input<MouseButton>>,
+    key_input: Res<Input<KeyCode>>,
+    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera, &mut PanOrbitHistory)>,
+) {
+    for (entity, mut pan_orbit, mut history) in orbit_cameras.iter_mut() {
+        if pan_orbit.enabled && active_cam.entity == Some(entity) {
+            let just_pressed = |button: Option<MouseButton>, key: Option<KeyCode>| {
+                button.is_some_and(|button| mouse_input.just_pressed(button))
+                    || key.is_some_and(|key| key_input.just_pressed(key))
+            };
+            if just_pressed(history.button_back, history.key_back) {
+                history.navigate_back();
+            }
+            if just_pressed(history.button_forward, history.key_forward) {
+                history.navigate_forward();
+            }
+        }
+
+        if history.pending {
+            history.pending = false;
+            if let Some(state) = history.current().copied() {
+                pan_orbit.set_target_orbit_state(state);
+            }
+        } else if pan_orbit.initialized && pan_orbit.at_rest {
+            let state = pan_orbit.orbit_state();
+            // Avoid triggering change detection when nothing needs to be recorded
+            if !history
+                .current()
+                .is_some_and(|current| same_view(current, &state))
+            {
+                history.record(state);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod record_tests {
+    use super::*;
+
+    fn state(alpha: f32) -> OrbitState {
+        OrbitState::new(Vec3::ZERO, alpha, 0.0, 5.0)
+    }
+
+    #[test]
+    fn navigates_back_and_forward() {
+        let mut history = PanOrbitHistory::default();
+        history.record(state(0.0));
+        history.record(state(1.0));
+        history.record(state(2.0));
+        assert!(history.navigate_back());
+        assert_eq!(history.current(), Some(&state(1.0)));
+        assert!(history.navigate_forward());
+        assert_eq!(history.current(), Some(&state(2.0)));
+        assert!(!history.navigate_forward());
+    }
+
+    #[test]
+    fn recording_after_going_back_drops_forward_entries() {
+        let mut history = PanOrbitHistory::default();
+        history.record(state(0.0));
+        history.record(state(1.0));
+        history.navigate_back();
+        history.record(state(3.0));
+        assert!(!history.can_navigate_forward());
+        assert!(history.navigate_back());
+        assert_eq!(history.current(), Some(&state(0.0)));
+    }
+
+    #[test]
+    fn drops_oldest_entries_and_ignores_duplicates() {
+        let mut history = PanOrbitHistory {
+            capacity: 2,
+            ..default()
+        };
+        history.record(state(0.0));
+        history.record(state(1.0));
+        history.record(state(1.0 + TAU));
+        history.record(state(2.0));
+        assert_eq!(history.entries, vec![state(1.0), state(2.0)]);
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::input::touchpad::{TouchpadMagnify, TouchpadRotate};
+use bevy::prelude::*;
+
+/// Mouse and touchpad input accumulated in `PreUpdate` since `pan_orbit_camera` last processed
+/// it. Buffering the input, rather than reading events directly, means no input is lost on
+/// frames where the camera systems don't run, and records how much time the input covers.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub(crate) struct InputBuffer {
+    /// Total mouse motion, in pixels
+    pub(crate) mouse_delta: Vec2,
+    /// Total scroll wheel input in lines, i.e. from a regular mouse wheel
+    pub(crate) scroll_line: Vec2,
+    /// Total scroll wheel input in pixels, i.e. from a touchpad
+    pub(crate) scroll_pixel: Vec2,
+    /// Total touchpad pinch (magnify) gesture input
+    pub(crate) magnify: f32,
+    /// Total touchpad rotate gesture input
+    pub(crate) rotate: f32,
+    /// How long the buffer has been accumulating input for, in real seconds
+    pub(crate) duration: f32,
+}
+
+impl InputBuffer {
+    /// Take the accumulated input, leaving the buffer empty
+    pub(crate) fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+}
+
+/// Adds this frame's input events to the `InputBuffer`
+pub(crate) fn accumulate_input(
+    time: Res<Time<Real>>,
+    mut buffer: ResMut<InputBuffer>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut scroll_events: EventReader<MouseWheel>,
+    mut magnify_events: EventReader<TouchpadMagnify>,
+    mut rotate_events: EventReader<TouchpadRotate>,
+) {
+    buffer.duration += time.delta_seconds();
+    buffer.mouse_delta += mouse_motion.read().map(|ev| ev.delta).sum::<Vec2>();
+    for ev in scroll_events.read() {
+        match ev.unit {
+            MouseScrollUnit::Line => buffer.scroll_line += Vec2::new(ev.x, ev.y),
+            MouseScrollUnit::Pixel => buffer.scroll_pixel += Vec2::new(ev.x, ev.y),
+        }
+    }
+    buffer.magnify += magnify_events.read().map(|ev| ev.0).sum::<f32>();
+    buffer.rotate += rotate_events.read().map(|ev| ev.0).sum::<f32>();
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+/// The most keys a `KeyChord` can contain
+const MAX_KEYS: usize = 4;
+
+/// The keys that count as modifiers for `Bindings::exclusive_modifiers`
+pub(crate) const MODIFIER_KEYS: [KeyCode; 8] = [
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::SuperLeft,
+    KeyCode::SuperRight,
+];
+
+/// A combination of up to four keys that must all be held at the same time, e.g. Ctrl+Shift.
+/// See `Bindings::chord_orbit` and friends.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyChord {
+    keys: [Option<KeyCode>; MAX_KEYS],
+}
+
+impl KeyChord {
+    /// Create a chord from the given keys. Only the first four keys are used.
+    pub fn new(keys: &[KeyCode]) -> Self {
+        let mut chord = KeyChord::default();
+        for key in keys.iter().take(MAX_KEYS) {
+            chord = chord.with(Some(*key));
+        }
+        chord
+    }
+
+    /// The keys in this chord.
+    pub fn keys(&self) -> impl Iterator<Item = KeyCode> + '_ {
+        self.keys.iter().flatten().copied()
+    }
+
+    /// Whether this chord has no keys, i.e. it is always considered held.
+    pub fn is_empty(&self) -> bool {
+        self.keys().next().is_none()
+    }
+
+    /// Whether `key` is part of this chord.
+    pub fn contains(&self, key: KeyCode) -> bool {
+        self.keys().any(|k| k == key)
+    }
+
+    /// Whether all the keys in this chord are currently held.
+    pub fn pressed(&self, key_input: &Input<KeyCode>) -> bool {
+        self.keys().all(|key| key_input.pressed(key))
+    }
+
+    /// Whether every key in this chord is also in `other`
+    pub(crate) fn is_subset_of(&self, other: &KeyChord) -> bool {
+        self.keys().all(|key| other.contains(key))
+    }
+
+    /// This chord with `key` added, if it isn't already in it and there is room for it
+    pub(crate) fn with(mut self, key: Option<KeyCode>) -> Self {
+        if let Some(key) = key.filter(|key| !self.contains(*key)) {
+            if let Some(slot) = self.keys.iter_mut().find(|slot| slot.is_none()) {
+                *slot = Some(key);
+            }
+        }
+        self
+    }
+}
+
+impl From<KeyCode> for KeyChord {
+    fn from(key: KeyCode) -> Self {
+        KeyChord::new(&[key])
+    }
+}
+
+#[cfg(test)]
+mod key_chord_tests {
+    use super::*;
+
+    #[test]
+    fn pressed_requires_all_keys() {
+        let chord = KeyChord::new(&[KeyCode::ControlLeft, KeyCode::ShiftLeft]);
+        let mut key_input = Input::<KeyCode>::default();
+        key_input.press(KeyCode::ControlLeft);
+        assert!(!chord.pressed(&key_input));
+        key_input.press(KeyCode::ShiftLeft);
+        assert!(chord.pressed(&key_input));
+    }
+
+    #[test]
+    fn empty_chord_is_always_pressed() {
+        let chord = KeyChord::default();
+        assert!(chord.is_empty());
+        assert!(chord.pressed(&Input::<KeyCode>::default()));
+    }
+
+    #[test]
+    fn with_ignores_duplicates() {
+        let chord = KeyChord::from(KeyCode::AltLeft).with(Some(KeyCode::AltLeft));
+        assert_eq!(chord.keys().count(), 1);
+        assert!(chord.is_subset_of(&KeyChord::new(&[KeyCode::AltLeft, KeyCode::ShiftLeft])));
+    }
+}
@@ -2,14 +2,92 @@
 #![allow(clippy::too_many_arguments)]
 #![doc = include_str!("../README.md")]
 
-use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
-use bevy::input::touchpad::{TouchpadMagnify, TouchpadRotate};
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::MouseWheel;
+use bevy::input::InputSystem;
 use bevy::prelude::*;
 use bevy::render::camera::RenderTarget;
-use bevy::window::{PrimaryWindow, WindowRef};
+use bevy::render::primitives::Aabb;
+use bevy::transform::TransformSystem;
+use bevy::window::{
+    CursorLeft, PrimaryWindow, WindowFocused, WindowRef, WindowResized, WindowScaleFactorChanged,
+};
 use std::f32::consts::{PI, TAU};
 
+pub use adopt::PanOrbitAdopt;
+pub use animation::{PanOrbitAnimation, PanOrbitKeyframe};
+pub use blend::{PanOrbitBlend, PanOrbitHandoff};
+pub use easing::Easing;
+#[cfg(feature = "bevy_egui")]
+pub use egui_viewport::{EguiFitViewport, EguiViewportCamera};
+pub use fly::FlyMode;
+pub use focus_height::PanOrbitFocusHeight;
+#[cfg(feature = "focus_indicator")]
+pub use focus_indicator::PanOrbitFocusIndicator;
+pub use focus_provider::{FocusProvider, FocusProviderPlugin, RecenterFocus};
+pub use follow::PanOrbitFollow;
+pub use group::PanOrbitGroup;
+pub use history::PanOrbitHistory;
+pub use key_chord::KeyChord;
+pub use long_press::PanOrbitLongPress;
+#[cfg(feature = "navigation_gizmo")]
+pub use navigation_gizmo::PanOrbitNavigationGizmo;
+pub use origin::OriginRebased;
+#[cfg(feature = "bevy_mod_picking")]
+pub use picking::PanOrbitPickingSettings;
+#[cfg(feature = "pivot_handle")]
+pub use pivot_handle::PanOrbitPivotHandle;
+#[cfg(feature = "quad_view")]
+pub use quad_view::{QuadViewPlugin, QuadViewSettings, QuadViewport};
+pub use recording::{
+    CameraRecording, PanOrbitPlayback, PanOrbitRecorder, PanOrbitRecordingPlugin, RecordedFrame,
+};
+pub use rts::RtsCameraController;
+pub use settings::{Bindings, Limits, OrbitSettings, PanSettings, ZoomSettings};
+pub use state::OrbitState;
+#[cfg(feature = "touch_controls")]
+pub use touch_controls::PanOrbitTouchControls;
+pub use util::{lerp_and_snap_f32, lerp_and_snap_vec3, pixels_to_world_pan};
+pub use validate::PanOrbitCameraError;
+pub use velocity::PanOrbitVelocity;
+
+mod adopt;
+mod animation;
+mod blend;
+mod camera_2d;
+mod easing;
+#[cfg(feature = "bevy_egui")]
+mod egui_viewport;
+mod fly;
+mod focus_height;
+#[cfg(feature = "focus_indicator")]
+mod focus_indicator;
+mod focus_provider;
+mod follow;
+mod group;
+mod history;
+mod input;
+mod key_chord;
+mod long_press;
+#[cfg(feature = "navigation_gizmo")]
+mod navigation_gizmo;
+mod origin;
+#[cfg(feature = "bevy_mod_picking")]
+mod picking;
+#[cfg(feature = "pivot_handle")]
+mod pivot_handle;
+#[cfg(feature = "quad_view")]
+mod quad_view;
+mod recording;
+mod resync;
+mod rts;
+mod settings;
+mod state;
+#[cfg(feature = "touch_controls")]
+mod touch_controls;
 mod util;
+mod validate;
+mod velocity;
 
 /// Bevy plugin that contains the systems for controlling `PanOrbitCamera` components.
 /// # Example
@@ -28,27 +106,304 @@ pub struct PanOrbitCameraPlugin;
 impl Plugin for PanOrbitCameraPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(ActiveCameraData::default())
+            .init_resource::<CycleActiveCameraKeys>()
+            .init_resource::<input::InputBuffer>()
+            .init_resource::<PanOrbitCameraSettings>()
+            .init_resource::<PanOrbitZoomBounds>()
+            .init_resource::<CameraInputConsumed>()
+            .add_event::<PanOrbitCommand>()
+            .add_event::<CycleActiveCamera>()
+            .add_event::<ActiveCameraChanged>()
+            .add_event::<ClickedWithoutDrag>()
+            .add_event::<CameraTargetLost>()
+            .add_event::<PanOrbitCameraError>()
+            .add_event::<OriginRebased>()
+            .add_event::<PanOrbitHandoff>()
+            .register_type::<PanOrbitCamera>()
+            .register_type::<ActiveCameraData>()
+            .register_type::<CycleActiveCameraKeys>()
+            .register_type::<PanOrbitCameraSettings>()
+            .register_type::<PanOrbitZoomBounds>()
+            .register_type::<CameraInputConsumed>()
+            .register_type::<PanOrbitGroup>()
+            .register_type::<RtsCameraController>()
+            .register_type::<FlyMode>()
+            .register_type::<PanOrbitVelocity>()
+            .register_type::<PanOrbitFollow>()
+            .register_type::<PanOrbitAnimation>()
+            .register_type::<PanOrbitFocusHeight>()
+            .register_type::<PanOrbitHistory>()
+            .register_type::<PanOrbitLongPress>()
+            .register_type::<PanOrbitAdopt>()
+            .register_type::<PanOrbitBlend>()
+            .register_type::<OrbitState>()
+            .register_type::<Easing>()
+            .register_type::<PanOrbitCommand>()
+            .register_type::<CycleActiveCamera>()
+            .register_type::<ActiveCameraChanged>()
+            .register_type::<ClickedWithoutDrag>()
+            .register_type::<CameraTargetLost>()
+            .register_type::<PanOrbitCameraError>()
+            .register_type::<OriginRebased>()
+            .register_type::<PanOrbitHandoff>()
             .add_systems(
+                PreUpdate,
+                (
+                    camera_2d::prepare_2d_cameras.before(initialize_pan_orbit_cameras),
+                    initialize_pan_orbit_cameras,
+                    input::accumulate_input
+                        .after(InputSystem)
+                        .run_if(systems_active),
+                ),
+            )
+            .configure_sets(
                 Update,
                 (
-                    active_viewport_data
-                        .run_if(|active_cam: Res<ActiveCameraData>| !active_cam.manual),
-                    pan_orbit_camera,
+                    PanOrbitCameraSubSet::InputGather,
+                    PanOrbitCameraSubSet::ProcessMotion,
+                    PanOrbitCameraSubSet::ApplyTransform,
                 )
                     .chain()
                     .in_set(PanOrbitCameraSystemSet),
+            )
+            .configure_sets(Update, PanOrbitCameraSystemSet.run_if(systems_active))
+            .configure_sets(
+                Update,
+                OriginRebaseSystemSet.before(PanOrbitCameraSystemSet),
+            )
+            .add_systems(
+                Update,
+                (
+                    // Not in the base set, which doesn't run until there is a `PanOrbitCamera`
+                    adopt::adopt_cameras.before(PanOrbitCameraSystemSet),
+                    // Not in the base set either, so the offset isn't lost while it's paused
+                    origin::rebase_origin.in_set(OriginRebaseSystemSet),
+                    handle_lost_camera_targets
+                        .before(active_viewport_data)
+                        .in_set(PanOrbitCameraSubSet::InputGather),
+                    (
+                        active_viewport_data,
+                        refresh_active_camera_sizes,
+                        cycle_active_camera,
+                        track_active_drag,
+                    )
+                        .chain()
+                        .run_if(|active_cam: Res<ActiveCameraData>| !active_cam.manual)
+                        .in_set(PanOrbitCameraSubSet::InputGather),
+                    blend::hand_off_cameras
+                        .after(track_active_drag)
+                        .before(send_active_camera_changed)
+                        .in_set(PanOrbitCameraSubSet::InputGather),
+                    send_active_camera_changed
+                        .after(cycle_active_camera)
+                        .in_set(PanOrbitCameraSubSet::InputGather),
+                    (
+                        initialize_pan_orbit_cameras,
+                        resync::resync_changed_transforms,
+                        animation::animate_pan_orbit_cameras,
+                        blend::animate_blends,
+                        follow::follow_targets,
+                        long_press::long_press_orbit,
+                        rts::rts_camera_controller,
+                        fly::fly_mode_controller,
+                        pan_orbit_camera,
+                        focus_height::apply_focus_heights,
+                        group::sync_groups,
+                        history::update_history,
+                    )
+                        .chain()
+                        .in_set(PanOrbitCameraSubSet::ProcessMotion),
+                    (
+                        validate::validate_pan_orbit_cameras,
+                        apply_pan_orbit_transforms,
+                        update_clip_planes,
+                    )
+                        .chain()
+                        .run_if(|settings: Res<PanOrbitCameraSettings>| {
+                            !settings.late_transform_update
+                        })
+                        .in_set(PanOrbitCameraSubSet::ApplyTransform),
+                ),
+            )
+            .configure_sets(
+                PostUpdate,
+                PanOrbitCameraSubSet::ApplyTransform
+                    .in_set(PanOrbitCameraSystemSet)
+                    .before(TransformSystem::TransformPropagate),
+            )
+            .configure_sets(PostUpdate, PanOrbitCameraSystemSet.run_if(systems_active))
+            .add_systems(
+                PostUpdate,
+                (
+                    validate::validate_pan_orbit_cameras,
+                    apply_pan_orbit_transforms,
+                    update_clip_planes,
+                )
+                    .chain()
+                    .run_if(|settings: Res<PanOrbitCameraSettings>| settings.late_transform_update)
+                    .in_set(PanOrbitCameraSubSet::ApplyTransform),
+            );
+
+        #[cfg(feature = "bevy_egui")]
+        app.register_type::<EguiViewportCamera>()
+            .register_type::<EguiFitViewport>()
+            .add_systems(
+                Update,
+                (
+                    egui_viewport::fit_viewports_to_egui_panels
+                        .before(active_viewport_data)
+                        .in_set(PanOrbitCameraSubSet::InputGather),
+                    egui_viewport::egui_viewport_data
+                        .after(refresh_active_camera_sizes)
+                        .before(cycle_active_camera)
+                        .run_if(|active_cam: Res<ActiveCameraData>| !active_cam.manual)
+                        .in_set(PanOrbitCameraSubSet::InputGather),
+                ),
+            );
+
+        #[cfg(feature = "bevy_mod_picking")]
+        app.init_resource::<PanOrbitPickingSettings>()
+            .register_type::<PanOrbitPickingSettings>()
+            .init_resource::<picking::PickingDragState>()
+            .add_systems(
+                Update,
+                picking::update_picking_drag_state.in_set(PanOrbitCameraSubSet::InputGather),
+            );
+
+        #[cfg(feature = "focus_indicator")]
+        app.register_type::<PanOrbitFocusIndicator>().add_systems(
+            Update,
+            focus_indicator::draw_focus_indicators
+                .in_set(PanOrbitCameraSubSet::ApplyTransform)
+                .after(apply_pan_orbit_transforms),
+        );
+
+        #[cfg(feature = "pivot_handle")]
+        app.init_resource::<pivot_handle::PivotHandleDragState>()
+            .register_type::<PanOrbitPivotHandle>()
+            .add_systems(
+                Update,
+                (
+                    pivot_handle::drag_pivot_handles
+                        .in_set(PanOrbitCameraSubSet::ProcessMotion)
+                        .before(pan_orbit_camera),
+                    pivot_handle::draw_pivot_handles
+                        .in_set(PanOrbitCameraSubSet::ApplyTransform)
+                        .after(apply_pan_orbit_transforms),
+                ),
             );
+
+        #[cfg(feature = "navigation_gizmo")]
+        app.register_type::<PanOrbitNavigationGizmo>().add_systems(
+            Update,
+            (
+                navigation_gizmo::spawn_navigation_gizmos.in_set(PanOrbitCameraSystemSet),
+                // Not in the base set, so gizmos are cleaned up even when its systems don't run
+                navigation_gizmo::despawn_navigation_gizmos,
+                navigation_gizmo::navigation_gizmo_clicks
+                    .in_set(PanOrbitCameraSubSet::ProcessMotion)
+                    .before(pan_orbit_camera),
+                navigation_gizmo::update_navigation_gizmos
+                    .in_set(PanOrbitCameraSubSet::ApplyTransform)
+                    .after(apply_pan_orbit_transforms),
+            ),
+        );
+
+        #[cfg(feature = "touch_controls")]
+        app.register_type::<PanOrbitTouchControls>().add_systems(
+            Update,
+            (
+                // Not in the base set, so the overlay is updated even when its systems don't run
+                touch_controls::sync_touch_controls,
+                touch_controls::touch_controls_input
+                    .run_if(resource_exists::<PanOrbitTouchControls>())
+                    .in_set(PanOrbitCameraSubSet::InputGather)
+                    .after(cycle_active_camera),
+            ),
+        );
+    }
+}
+
+/// Global settings for `PanOrbitCameraPlugin`, inserted automatically by the plugin.
+#[derive(Resource, Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct PanOrbitCameraSettings {
+    /// If `true`, none of the plugin's systems run, so cameras don't respond to input or move,
+    /// and the plugin costs nothing, e.g. when the orbit camera is only used in an editor mode.
+    /// The systems also don't run while there are no `PanOrbitCamera`s. Defaults to `false`.
+    pub paused: bool,
+    /// If `true`, camera transforms are written in `PostUpdate`, right before transform
+    /// propagation, rather than at the end of the plugin's systems in `Update`. This picks up
+    /// changes made to `PanOrbitCamera` by any system in `Update`, regardless of system order,
+    /// so they are rendered in the same frame. Systems that read the camera's `Transform` in
+    /// `Update` will see the previous frame's transform. Input is always gathered in `PreUpdate`.
+    /// Defaults to `false`.
+    pub late_transform_update: bool,
+}
+
+/// The zoom limits used for cameras that don't set their own, inserted automatically by
+/// `PanOrbitCameraPlugin`.
+#[derive(Resource, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct PanOrbitZoomBounds {
+    /// The smallest radius or scale that any camera can zoom to, since a zoom of `0.0` causes
+    /// problems. This is also the lower zoom limit of orthographic cameras that don't have a
+    /// `Limits::zoom_lower`. Defaults to `0.05`.
+    pub min_zoom_distance: f32,
+    /// Perspective cameras that don't have a `Limits::zoom_lower` can't zoom closer
+    /// than their near plane times this, so the focus isn't clipped by the near plane.
+    /// Defaults to `2.0`.
+    pub near_plane_zoom_factor: f32,
+}
+
+impl Default for PanOrbitZoomBounds {
+    fn default() -> Self {
+        PanOrbitZoomBounds {
+            min_zoom_distance: 0.05,
+            near_plane_zoom_factor: 2.0,
+        }
     }
 }
 
+/// Whether the plugin's systems should run
+fn systems_active(
+    settings: Res<PanOrbitCameraSettings>,
+    orbit_cameras: Query<(), With<PanOrbitCamera>>,
+) -> bool {
+    !settings.paused && !orbit_cameras.is_empty()
+}
+
 /// Base system set to allow ordering of `PanOrbitCamera`
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct PanOrbitCameraSystemSet;
 
+/// System set that moves cameras along with the world when `OriginRebased` is sent. It runs
+/// before `PanOrbitCameraSystemSet`, even while the plugin is paused. Systems that send
+/// `OriginRebased` should run before it, so the cameras move in the same frame as the world.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct OriginRebaseSystemSet;
+
+/// Sub-sets of `PanOrbitCameraSystemSet`, which run in the order listed here. Use these to run
+/// your own systems at a specific point, e.g. to modify the target values after input has been
+/// processed but before the transform is updated.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum PanOrbitCameraSubSet {
+    /// Works out which camera is active, i.e. which camera input is meant for.
+    InputGather,
+    /// Turns input, commands, animations, etc. into new target values.
+    ProcessMotion,
+    /// Moves the cameras towards their target values, and updates their transform and projection.
+    /// This is in `PostUpdate` instead if `PanOrbitCameraSettings::late_transform_update` is set.
+    ApplyTransform,
+}
+
 /// Tags an entity as capable of panning and orbiting, and provides a way to configure the
 /// camera's behaviour and controls.
 /// The entity must have `Transform` and `Projection` components. Typically you would add a
 /// `Camera3dBundle` which already contains these.
+/// It can also be added to a `Camera2dBundle`, for 2D games and map tools. The camera is then
+/// locked to look straight down the negative Z axis, so it can only pan and zoom, and its
+/// `OrthographicProjection` is replaced with an equivalent `Projection` the frame it's spawned.
 /// # Example
 /// ```no_run
 /// # use bevy::prelude::*;
@@ -71,7 +426,8 @@ pub struct PanOrbitCameraSystemSet;
 ///         ));
 ///  }
 /// ```
-#[derive(Component, Copy, Clone, Debug, PartialEq)]
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
 pub struct PanOrbitCamera {
     /// The point to orbit around, and what the camera looks at. Updated automatically.
     /// If you want to change the focus programmatically after initialization, set `target_focus`
@@ -112,6 +468,19 @@ pub struct PanOrbitCamera {
     /// the mouse controls, e.g. with the keyboard.
     /// Defaults to `Vec3::ZERO`.
     pub target_focus: Vec3,
+    /// Shifts the camera in its local space (X is right, Y is up) without changing the point it
+    /// orbits around. For example, in a third person game, orbit around the character with
+    /// `focus`, and use an offset of `Vec3::Y` to aim slightly above their head, or `Vec3::X` for
+    /// an over-the-shoulder view. If you change this after initialization, also set
+    /// `force_update` to `true`. Defaults to `Vec3::ZERO`.
+    pub focus_offset: Vec3,
+    /// The orientation that `alpha` and `beta` are relative to. With the default of
+    /// `Quat::IDENTITY`, alpha and beta of `0.0` look along -Z with +Y up. Change this to align
+    /// the camera with scenes that use a different convention, e.g.
+    /// `Quat::from_rotation_x(PI / 2.0)` for Z-up scenes, or to make `0.0` face the "front" of an
+    /// imported model. Set `force_update` after changing this at runtime.
+    /// Defaults to `Quat::IDENTITY`.
+    pub orientation_offset: Quat,
     /// The target alpha value. The camera will smoothly transition to this value. Updated
     /// automatically, but you can also update it manually to control the camera independently of
     /// the mouse controls, e.g. with the keyboard.
@@ -127,88 +496,104 @@ pub struct PanOrbitCamera {
     /// the mouse controls, e.g. with the keyboard.
     /// Defaults to `1.0`.
     pub target_radius: f32,
+    /// The current rotation around the view axis, in radians. Positive values rotate the view
+    /// counter-clockwise. You should not update this after initialization - use `target_roll`
+    /// instead. Defaults to `0.0`.
+    pub roll: f32,
+    /// The target roll value. The camera will smoothly transition to this value, using
+    /// `orbit.smoothness`. Defaults to `0.0`.
+    pub target_roll: f32,
     /// The target scale for orthographic projection. The camera will smoothly transition to this value.
     /// This field is only applicable with Orthographic cameras.
     /// Updated automatically, but you can also update it manually to control the camera independently
     /// of the mouse controls, e.g. with the keyboard.
     /// Defaults to `1.0`.
     pub target_scale: f32,
-    /// Upper limit on the `alpha` value, in radians. Use this to restrict the maximum rotation
-    /// around the global Y axis.
-    /// Defaults to `None`.
-    pub alpha_upper_limit: Option<f32>,
-    /// Lower limit on the `alpha` value, in radians. Use this to restrict the maximum rotation
-    /// around the global Y axis.
-    /// Defaults to `None`.
-    pub alpha_lower_limit: Option<f32>,
-    /// Upper limit on the `beta` value, in radians. Use this to restrict the maximum rotation
-    /// around the local X axis.
-    /// Defaults to `None`.
-    pub beta_upper_limit: Option<f32>,
-    /// Lower limit on the `beta` value, in radians. Use this to restrict the maximum rotation
-    /// around the local X axis.
-    /// Defaults to `None`.
-    pub beta_lower_limit: Option<f32>,
-    /// Upper limit on the zoom. This applies to `radius`, in the case of using a perspective
-    /// camera, or the projection scale in the case of using an orthographic
-    /// camera. Note that the zoom value (radius or scale) will never go below `0.02`.
-    /// Defaults to `None`.
-    pub zoom_upper_limit: Option<f32>,
-    /// Lower limit on the zoom. This applies to `radius`, in the case of using a perspective
-    /// camera, or the projection scale in the case of using an orthographic
-    /// camera. Note that the zoom value (radius or scale) will never go below `0.02`.
+    /// Orbit settings, such as the sensitivity, smoothness, and inversion of orbiting.
+    pub orbit: OrbitSettings,
+    /// Pan settings, such as the sensitivity, smoothness, and edge panning.
+    pub pan: PanSettings,
+    /// Zoom settings, such as the sensitivity, smoothness, and zooming to the cursor.
+    pub zoom: ZoomSettings,
+    /// Limits on the alpha, beta, zoom, and focus.
+    pub limits: Limits,
+    /// The mouse buttons and keys that control the camera.
+    pub bindings: Bindings,
+    /// If set, the near clipping plane of a perspective projection is set to `radius` multiplied
+    /// by this value whenever the radius changes, so zooming in very close doesn't clip through
+    /// objects. E.g. `0.01` puts the near plane at 1% of the distance to the focus.
+    /// Defaults to `None` (the projection's near plane is left alone).
+    pub near_plane_ratio: Option<f32>,
+    /// If set, the far clipping plane of a perspective projection is set to `radius` multiplied
+    /// by this value whenever the radius changes, so zooming out over large scenes doesn't cut
+    /// off distant objects, while keeping depth precision reasonable to avoid z-fighting.
+    /// Defaults to `None` (the projection's far plane is left alone).
+    pub far_plane_ratio: Option<f32>,
+    /// The current orbit velocity in radians per second when `orbit.velocity_mode` is enabled,
+    /// where `x` applies to `alpha` and `y` to `beta`. Updated automatically.
+    pub orbit_velocity: Vec2,
+    /// How the camera moves towards its target values. `SmoothingModel::Spring` gives natural
+    /// overshoot and settle behavior, e.g. for cinematic cameras, in which case
+    /// `orbit.smoothness`, `pan.smoothness`, and `zoom.smoothness` are ignored.
+    /// Defaults to `SmoothingModel::Lerp`.
+    pub smoothing_model: SmoothingModel,
+    /// How close a value needs to get to its target before it snaps to it, ending the smooth
+    /// motion. Increase this for very large scenes, where values might otherwise never snap, and
+    /// decrease it for very small scenes, where they would snap too early. With
+    /// `SmoothingModel::Spring`, the velocity also has to be below it.
+    /// Defaults to `0.001`.
+    pub snap_threshold: f32,
+    /// The camera's `Transform` is only written when its translation would move further than
+    /// this, or its rotation would turn by more than this many radians, so systems relying on
+    /// `Changed<Transform>`, e.g. for network sync or caching, don't see changes every frame
+    /// while the camera is barely moving. Set to `0.0` to write every change.
+    /// Defaults to `0.00001`.
+    pub transform_epsilon: f32,
+    /// The velocities of the values animated by `SmoothingModel::Spring`. Updated automatically.
+    pub spring_state: SpringState,
+    /// Which clock to use for smoothing, zoom momentum, and `PanOrbitAnimation` playback.
+    /// Smoothness values are per frame at 60 FPS, and are adjusted for the actual frame time, so
+    /// the camera moves the same regardless of frame rate.
+    /// Defaults to `TimeSource::Real`, i.e. the camera keeps moving while the game is paused.
+    pub time_source: TimeSource,
+    /// Multiplier for the speed of smoothing, `SmoothnessProfile` transitions, and
+    /// `PanOrbitAnimation`, `PanOrbitBlend`, and `PanOrbitPlayback` playback on this camera, e.g.
+    /// `0.5` for slow, graceful moves on a cinematic camera, without changing the game's time.
+    /// Input itself is not affected. Defaults to `1.0`.
+    pub animation_speed: f32,
+    /// The smoothness profile that the smoothness values are currently transitioning to. Set this
+    /// with `set_smoothness_profile`. Automatically set to `None` once the transition is complete.
     /// Defaults to `None`.
-    pub zoom_lower_limit: Option<f32>,
-    /// Upper limit on the X position of the camera focus point. Defaults to `None`.
-    pub focus_x_upper_limit: Option<f32>,
-    /// Lower limit on the X position of the camera focus point. Defaults to `None`.
-    pub focus_x_lower_limit: Option<f32>,
-    /// Upper limit on the Y position of the camera focus point. Defaults to `None`.
-    pub focus_y_upper_limit: Option<f32>,
-    /// Lower limit on the Y position of the camera focus point. Defaults to `None`.
-    pub focus_y_lower_limit: Option<f32>,
-    /// Upper limit on the Z position of the camera focus point. Defaults to `None`.
-    pub focus_z_upper_limit: Option<f32>,
-    /// Lower limit on the Z position of the camera focus point. Defaults to `None`.
-    pub focus_z_lower_limit: Option<f32>,
-    /// The sensitivity of the orbiting motion. Defaults to `1.0`.
-    pub orbit_sensitivity: f32,
-    /// How much smoothing is applied to the orbit motion. A value of `0.0` disables smoothing,
-    /// so there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite
-    /// smoothing. Defaults to `0.8`.
-    pub orbit_smoothness: f32,
-    /// The sensitivity of the panning motion. Defaults to `1.0`.
-    pub pan_sensitivity: f32,
-    /// How much smoothing is applied to the panning motion. A value of `0.0` disables smoothing,
-    /// so there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite
-    /// smoothing. Defaults to `0.6`.
-    pub pan_smoothness: f32,
-    /// The sensitivity of moving the camera closer or further way using the scroll wheel. Defaults to `1.0`.
-    pub zoom_sensitivity: f32,
-    /// How much smoothing is applied to the zoom motion. A value of `0.0` disables smoothing,
-    /// so there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite
-    /// smoothing. Defaults to `0.8`.
-    /// Note that this setting does not apply to pixel-based scroll events, as they are typically
-    /// already smooth. It only applies to line-based scroll events.
-    pub zoom_smoothness: f32,
-    /// Button used to orbit the camera. Defaults to `Button::Left`.
-    pub button_orbit: MouseButton,
-    /// Button used to pan the camera. Defaults to `Button::Right`.
-    pub button_pan: MouseButton,
-    /// Key that must be pressed for `button_orbit` to work. Defaults to `None` (no modifier).
-    pub modifier_orbit: Option<KeyCode>,
-    /// Key that must be pressed for `button_pan` to work. Defaults to `None` (no modifier).
-    pub modifier_pan: Option<KeyCode>,
-    /// Key that must be pressed for the pan gesture to work on touchpad. Defaults to `None` (no modifier).
-    pub modifier_orbit_touchpad: Option<KeyCode>,
-    /// Whether to reverse the zoom direction. Defaults to `false`.
-    pub reversed_zoom: bool,
+    pub smoothness_transition: Option<SmoothnessProfile>,
+    /// The current kinetic zoom velocity, as a fraction of the zoom value per second. Only used
+    /// when `zoom.kinetic_friction` is set. Updated automatically.
+    pub zoom_velocity: f32,
+    /// The current kinetic pan velocity, in world units per second. Only used when
+    /// `pan.kinetic_friction` is set. Updated automatically.
+    pub pan_velocity: Vec3,
+    /// If `true`, the camera frames the whole scene, i.e. the combined bounds of all entities
+    /// with an `Aabb`, as soon as any exist, by setting the focus and radius. The current
+    /// direction is kept. Useful for viewers that load arbitrary models. Since `Aabb`s are only
+    /// added once meshes have loaded, this may happen a few frames after initialization.
+    /// Automatically set back to `false` once the scene has been framed.
+    /// Defaults to `false`.
+    pub auto_frame_on_init: bool,
+    /// If `true`, when the camera's `Transform` is changed by something other than the plugin,
+    /// e.g. physics or a cutscene, the focus, alpha, beta, and roll are recalculated from it with
+    /// `resync_from_transform`, so the camera continues from there instead of snapping back.
+    /// Change the transform before `PanOrbitCameraSystemSet` runs. Defaults to `false`.
+    pub auto_resync_transform: bool,
     /// Whether the camera is currently upside down. Updated automatically. Should not be set manually.
     pub is_upside_down: bool,
-    /// Whether to allow the camera to go upside down. Defaults to `false`.
-    pub allow_upside_down: bool,
+    /// What the user is currently doing with the camera. Updated automatically. Use
+    /// `is_orbiting`, `is_panning`, `is_zooming`, and `current_velocity` to read it.
+    pub drag_state: DragState,
     /// If `false`, disable control of the camera. Defaults to `true`.
     pub enabled: bool,
+    /// If `true`, the camera keeps receiving input while the pointer is over egui areas, e.g. for
+    /// a background camera that should always respond. Only has an effect with the `bevy_egui`
+    /// feature. Defaults to `false`.
+    pub ignore_egui: bool,
     /// Whether `PanOrbitCamera` has been initialized with the initial config.
     /// Set to `true` if you want the camera to smoothly animate to its initial position.
     /// Defaults to `false`.
@@ -218,6 +603,10 @@ pub struct PanOrbitCamera {
     /// This will be automatically set back to `false` after one frame.
     /// Defaults to `false`.
     pub force_update: bool,
+    /// Whether the camera has reached its target values. Cameras that are at rest, haven't been
+    /// changed, and receive no input are skipped entirely, so idle cameras don't cost anything
+    /// or trigger change detection on their `Transform`. Updated automatically.
+    pub at_rest: bool,
 }
 
 impl Default for PanOrbitCamera {
@@ -225,43 +614,655 @@ impl Default for PanOrbitCamera {
         PanOrbitCamera {
             focus: Vec3::ZERO,
             target_focus: Vec3::ZERO,
+            focus_offset: Vec3::ZERO,
+            orientation_offset: Quat::IDENTITY,
             radius: None,
             is_upside_down: false,
-            allow_upside_down: false,
-            orbit_sensitivity: 1.0,
-            orbit_smoothness: 0.8,
-            pan_sensitivity: 1.0,
-            pan_smoothness: 0.6,
-            zoom_sensitivity: 1.0,
-            zoom_smoothness: 0.8,
-            button_orbit: MouseButton::Left,
-            button_pan: MouseButton::Right,
-            modifier_orbit: None,
-            modifier_pan: None,
-            modifier_orbit_touchpad: None,
-            reversed_zoom: false,
+            drag_state: DragState::default(),
+            orbit_velocity: Vec2::ZERO,
+            time_source: TimeSource::Real,
+            animation_speed: 1.0,
+            smoothness_transition: None,
+            smoothing_model: SmoothingModel::Lerp,
+            snap_threshold: 0.001,
+            transform_epsilon: 0.00001,
+            spring_state: SpringState::default(),
+            zoom_velocity: 0.0,
+            pan_velocity: Vec3::ZERO,
+            auto_frame_on_init: false,
+            auto_resync_transform: false,
             enabled: true,
+            ignore_egui: false,
             alpha: None,
             beta: None,
             scale: None,
             target_alpha: 0.0,
             target_beta: 0.0,
+            roll: 0.0,
+            target_roll: 0.0,
             target_radius: 1.0,
             target_scale: 1.0,
+            orbit: OrbitSettings::default(),
+            pan: PanSettings::default(),
+            zoom: ZoomSettings::default(),
+            limits: Limits::default(),
+            bindings: Bindings::default(),
             initialized: false,
-            alpha_upper_limit: None,
-            alpha_lower_limit: None,
-            beta_upper_limit: None,
-            beta_lower_limit: None,
-            zoom_upper_limit: None,
-            zoom_lower_limit: None,
-            focus_x_upper_limit: None,
-            focus_x_lower_limit: None,
-            focus_y_upper_limit: None,
-            focus_y_lower_limit: None,
-            focus_z_upper_limit: None,
-            focus_z_lower_limit: None,
+            near_plane_ratio: None,
+            far_plane_ratio: None,
             force_update: false,
+            at_rest: false,
+        }
+    }
+}
+
+impl PanOrbitCamera {
+    /// A camera that looks at `focus` from the given yaw (alpha) and pitch (beta), in radians,
+    /// and distance, with every other field at its default value. Both the current and target
+    /// values are set, so use `transform` to place the camera there from the very first frame,
+    /// rather than positioning the `Transform` manually.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::PanOrbitCamera;
+    /// fn setup(mut commands: Commands) {
+    ///     let pan_orbit = PanOrbitCamera::from_yaw_pitch_radius(0.5, 0.3, 5.0, Vec3::ZERO);
+    ///     commands.spawn((
+    ///         Camera3dBundle {
+    ///             transform: pan_orbit.transform(),
+    ///             ..default()
+    ///         },
+    ///         pan_orbit,
+    ///     ));
+    /// }
+    /// ```
+    pub fn from_yaw_pitch_radius(yaw: f32, pitch: f32, radius: f32, focus: Vec3) -> Self {
+        PanOrbitCamera {
+            focus,
+            target_focus: focus,
+            alpha: Some(yaw),
+            beta: Some(pitch),
+            radius: Some(radius),
+            target_alpha: yaw,
+            target_beta: pitch,
+            target_radius: radius,
+            ..default()
+        }
+    }
+
+    /// The transform that matches the current focus, alpha, beta, radius, and roll, using the
+    /// target values for any that haven't been initialized yet.
+    pub fn transform(&self) -> Transform {
+        let state = self.orbit_state();
+        let mut transform = Transform::default();
+        util::update_orbit_transform(
+            state.alpha,
+            state.beta,
+            self.roll,
+            state.radius,
+            state.focus,
+            self.focus_offset,
+            self.orientation_offset,
+            &mut transform,
+        );
+        transform
+    }
+
+    /// Smoothly change `orbit.smoothness`, `pan.smoothness`, and `zoom.smoothness` to the values
+    /// of the given profile. The values are animated over a short period, so that switching
+    /// profiles while the camera is moving isn't jarring.
+    pub fn set_smoothness_profile(&mut self, profile: SmoothnessProfile) {
+        self.smoothness_transition = Some(profile);
+    }
+
+    /// Set `target_alpha` in degrees.
+    pub fn set_alpha_degrees(&mut self, degrees: f32) {
+        self.target_alpha = degrees.to_radians();
+    }
+
+    /// Set `target_beta` in degrees.
+    pub fn set_beta_degrees(&mut self, degrees: f32) {
+        self.target_beta = degrees.to_radians();
+    }
+
+    /// Set `limits.alpha_lower` and `limits.alpha_upper` in degrees. If `lower` is greater than
+    /// `upper`, they are swapped.
+    pub fn set_alpha_limits_degrees(&mut self, lower: f32, upper: f32) {
+        let (lower, upper) = (lower.min(upper), lower.max(upper));
+        self.limits.alpha_lower = Some(lower.to_radians());
+        self.limits.alpha_upper = Some(upper.to_radians());
+    }
+
+    /// Set `limits.beta_lower` and `limits.beta_upper` in degrees. If `lower` is greater than
+    /// `upper`, they are swapped.
+    pub fn set_beta_limits_degrees(&mut self, lower: f32, upper: f32) {
+        let (lower, upper) = (lower.min(upper), lower.max(upper));
+        self.limits.beta_lower = Some(lower.to_radians());
+        self.limits.beta_upper = Some(upper.to_radians());
+    }
+
+    /// The current yaw (`alpha`) in degrees, or the target if the camera hasn't been
+    /// initialized yet.
+    pub fn yaw_degrees(&self) -> f32 {
+        self.alpha.unwrap_or(self.target_alpha).to_degrees()
+    }
+
+    /// The current pitch (`beta`) in degrees, or the target if the camera hasn't been
+    /// initialized yet.
+    pub fn pitch_degrees(&self) -> f32 {
+        self.beta.unwrap_or(self.target_beta).to_degrees()
+    }
+
+    /// Set `target_alpha` to the given angle (in radians), choosing whichever direction of
+    /// rotation is shortest. For example, if `target_alpha` is `0.0`, setting it to 350 degrees
+    /// will rotate 10 degrees to the left rather than 350 degrees to the right.
+    pub fn set_target_alpha_wrapped(&mut self, alpha: f32) {
+        self.target_alpha += util::shortest_angle_delta(self.target_alpha, alpha);
+    }
+
+    /// The current focus, alpha, beta, and radius, or the targets if the camera hasn't been
+    /// initialized yet.
+    pub fn orbit_state(&self) -> OrbitState {
+        OrbitState {
+            focus: self.focus,
+            alpha: self.alpha.unwrap_or(self.target_alpha),
+            beta: self.beta.unwrap_or(self.target_beta),
+            radius: self.radius.unwrap_or(self.target_radius),
+        }
+    }
+
+    /// Move the camera to a blend of two states, where `t` of `0.0` gives `state_a` and `1.0`
+    /// gives `state_b`. This sets the target values, so smoothing still applies, and the camera
+    /// can be controlled as usual afterwards. See `PanOrbitBlend` for animating `t`.
+    pub fn blend_between(&mut self, state_a: OrbitState, state_b: OrbitState, t: f32) {
+        self.set_target_orbit_state(state_a.lerp(&state_b, t));
+    }
+
+    /// The target focus, alpha, beta, and radius, e.g. for saving where the camera is heading.
+    pub fn target_orbit_state(&self) -> OrbitState {
+        OrbitState {
+            focus: self.target_focus,
+            alpha: self.target_alpha,
+            beta: self.target_beta,
+            radius: self.target_radius,
+        }
+    }
+
+    /// Set the target focus, alpha, beta, and radius. Smoothing and limits still apply.
+    pub fn set_target_orbit_state(&mut self, state: OrbitState) {
+        self.target_focus = state.focus;
+        self.target_alpha = state.alpha;
+        self.target_beta = state.beta;
+        self.target_radius = state.radius;
+    }
+
+    /// Move the camera to the given state without any smoothing, e.g. when teleporting it for a
+    /// level change or respawn. Both the current and target values are set, any momentum is
+    /// cleared, and the transform is updated the next time `PanOrbitCameraSystemSet` runs, i.e.
+    /// in the same frame if this is called before it.
+    pub fn set_immediate(&mut self, alpha: f32, beta: f32, radius: f32, focus: Vec3) {
+        self.alpha = Some(alpha);
+        self.beta = Some(beta);
+        self.radius = Some(radius);
+        self.focus = focus;
+        self.target_alpha = alpha;
+        self.target_beta = beta;
+        self.target_radius = radius;
+        self.target_focus = focus;
+        self.zoom_velocity = 0.0;
+        self.orbit_velocity = Vec2::ZERO;
+        self.pan_velocity = Vec3::ZERO;
+        self.spring_state = SpringState::default();
+        self.force_update = true;
+    }
+
+    /// Set the focus without smoothing, e.g. when snapping it to a grid. Unlike setting
+    /// `target_focus`, the camera jumps straight to the new focus, while user input and other
+    /// values are still smoothed.
+    pub fn set_focus_immediate(&mut self, focus: Vec3) {
+        self.focus = focus;
+        self.target_focus = focus;
+        self.spring_state.focus = default();
+        self.pan_velocity = Vec3::ZERO;
+        self.force_update = true;
+    }
+
+    /// Set alpha without smoothing. See `set_focus_immediate`.
+    pub fn set_alpha_immediate(&mut self, alpha: f32) {
+        self.alpha = Some(alpha);
+        self.target_alpha = alpha;
+        self.orbit_velocity.x = 0.0;
+        self.spring_state.alpha = default();
+        self.force_update = true;
+    }
+
+    /// Set beta without smoothing. See `set_focus_immediate`.
+    pub fn set_beta_immediate(&mut self, beta: f32) {
+        self.beta = Some(beta);
+        self.target_beta = beta;
+        self.orbit_velocity.y = 0.0;
+        self.spring_state.beta = default();
+        self.force_update = true;
+    }
+
+    /// Set the radius without smoothing. See `set_focus_immediate`.
+    pub fn set_radius_immediate(&mut self, radius: f32) {
+        self.radius = Some(radius);
+        self.target_radius = radius;
+        self.zoom_velocity = 0.0;
+        self.spring_state.radius = default();
+        self.force_update = true;
+    }
+
+    /// Set the orthographic scale without smoothing. See `set_focus_immediate`.
+    pub fn set_scale_immediate(&mut self, scale: f32) {
+        self.scale = Some(scale);
+        self.target_scale = scale;
+        self.zoom_velocity = 0.0;
+        self.spring_state.scale = default();
+        self.force_update = true;
+    }
+
+    /// The right and up directions that panning moves the focus along, based on `pan.mode`
+    fn pan_axes(&self, transform: &Transform) -> (Vec3, Vec3) {
+        match self.pan.mode {
+            PanMode::CameraRelative => (transform.right(), transform.up()),
+            PanMode::WorldAligned => (Vec3::X, Vec3::NEG_Z),
+        }
+    }
+
+    /// The orbit `delta` with the axes locked by `OrbitSettings::lock_alpha` and
+    /// `OrbitSettings::lock_beta` zeroed out.
+    fn unlocked_orbit(&self, delta: Vec2) -> Vec2 {
+        Vec2::new(
+            if self.orbit.lock_alpha { 0.0 } else { delta.x },
+            if self.orbit.lock_beta { 0.0 } else { delta.y },
+        )
+    }
+
+    /// Move `target_focus` forward along the view direction by `distance` (or backward if
+    /// negative), keeping the radius the same, so the camera moves with it. Unlike zooming, this
+    /// lets the camera travel through the scene.
+    pub fn move_focus_forward(&mut self, distance: f32) {
+        let forward =
+            util::orbit_forward(self.target_alpha, self.target_beta, self.orientation_offset);
+        self.target_focus += forward * distance;
+    }
+
+    /// Whether the user is currently orbiting this camera.
+    pub fn is_orbiting(&self) -> bool {
+        self.drag_state.orbiting
+    }
+
+    /// Whether the user is currently panning this camera.
+    pub fn is_panning(&self) -> bool {
+        self.drag_state.panning
+    }
+
+    /// Whether the user is currently zooming this camera, either by dragging or by scrolling or
+    /// pinching this frame.
+    pub fn is_zooming(&self) -> bool {
+        self.drag_state.zooming
+    }
+
+    /// How fast the camera moved during the last frame, in world units per second. This includes
+    /// movement caused by smoothing, animation, etc., not just direct user input. See
+    /// `PanOrbitVelocity` for the angular and zoom velocities.
+    pub fn current_velocity(&self) -> Vec3 {
+        self.drag_state.velocity
+    }
+
+    /// Calculates the focus, alpha, beta, and radius that frame the given bounds when looking in
+    /// `direction`, e.g. for rendering thumbnails or screenshots of objects from a consistent
+    /// angle. `padding_fraction` adds space around the bounds, e.g. `0.1` for a 10% margin.
+    /// `bounds` are in world space, so for a mesh you need to transform its `Aabb` first.
+    /// Assign the results to the `target_*` fields to move the camera there.
+    /// The angles assume the default `orientation_offset`.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy::render::primitives::Aabb;
+    /// # use bevy_panorbit_camera::PanOrbitCamera;
+    /// fn frame_object(mut cameras: Query<(&mut PanOrbitCamera, &Projection)>) {
+    ///     let bounds = Aabb::from_min_max(Vec3::ZERO, Vec3::ONE);
+    ///     for (mut pan_orbit, projection) in cameras.iter_mut() {
+    ///         let Projection::Perspective(perspective) = projection else {
+    ///             continue;
+    ///         };
+    ///         let (focus, alpha, beta, radius) = PanOrbitCamera::look_at_bounds(
+    ///             &bounds,
+    ///             Vec3::new(-1.0, -1.0, -1.0),
+    ///             0.1,
+    ///             perspective,
+    ///         );
+    ///         pan_orbit.target_focus = focus;
+    ///         pan_orbit.target_alpha = alpha;
+    ///         pan_orbit.target_beta = beta;
+    ///         pan_orbit.target_radius = radius;
+    ///     }
+    /// }
+    /// ```
+    pub fn look_at_bounds(
+        bounds: &Aabb,
+        direction: Vec3,
+        padding_fraction: f32,
+        projection: &PerspectiveProjection,
+    ) -> (Vec3, f32, f32, f32) {
+        let (alpha, beta, radius) = util::fit_sphere(
+            bounds.half_extents.length(),
+            direction,
+            padding_fraction,
+            projection.fov,
+            projection.aspect_ratio,
+        );
+        (bounds.center.into(), alpha, beta, radius)
+    }
+
+    /// Returns the point under the cursor on the plane that goes through `focus`, perpendicular to
+    /// the view direction. Useful for placing objects at the same depth as the focus.
+    /// `camera` and `window` must be the `Camera` this component is attached to, and the window
+    /// it renders to. Returns `None` if the camera hasn't been initialized yet, or if the cursor
+    /// is not in the window.
+    pub fn cursor_to_focus_plane(&self, camera: &Camera, window: &Window) -> Option<Vec3> {
+        let (Some(alpha), Some(beta), Some(radius)) = (self.alpha, self.beta, self.radius) else {
+            return None;
+        };
+        let mut transform = Transform::default();
+        util::update_orbit_transform(
+            alpha,
+            beta,
+            self.roll,
+            radius,
+            self.focus,
+            self.focus_offset,
+            self.orientation_offset,
+            &mut transform,
+        );
+
+        let viewport_min = camera
+            .logical_viewport_rect()
+            .map_or(Vec2::ZERO, |rect| rect.min);
+        let cursor_pos = window.cursor_position()? - viewport_min;
+        let ray = camera.viewport_to_world(&GlobalTransform::from(transform), cursor_pos)?;
+        util::ray_plane_intersection(ray.origin, ray.direction, self.focus, transform.forward())
+    }
+}
+
+/// The interaction state of a `PanOrbitCamera`, updated by the plugin every frame. See
+/// `PanOrbitCamera::is_orbiting` and friends.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+pub struct DragState {
+    orbiting: bool,
+    panning: bool,
+    zooming: bool,
+    dragging: bool,
+    velocity: Vec3,
+    /// The button that was pressed, while it hasn't moved past `bindings.drag_threshold` yet
+    click_button: Option<MouseButton>,
+    /// How far the mouse has moved since `click_button` was pressed
+    click_distance: f32,
+}
+
+impl DragState {
+    /// Whether the camera is not being orbited, panned, zoomed, or dragged
+    fn is_idle(&self) -> bool {
+        !(self.orbiting || self.panning || self.zooming || self.dragging)
+    }
+}
+
+/// Presets for `orbit.smoothness`, `pan.smoothness`, and `zoom.smoothness`, which can be
+/// switched between at runtime using `PanOrbitCamera::set_smoothness_profile`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+pub enum SmoothnessProfile {
+    /// No smoothing at all, the camera follows input 1:1.
+    Instant,
+    /// A small amount of smoothing, for responsive controls.
+    Snappy,
+    /// A lot of smoothing, for slow and graceful camera moves.
+    Cinematic,
+    /// Custom smoothness values.
+    Custom {
+        /// The value for `orbit.smoothness`.
+        orbit: f32,
+        /// The value for `pan.smoothness`.
+        pan: f32,
+        /// The value for `zoom.smoothness`.
+        zoom: f32,
+    },
+}
+
+impl SmoothnessProfile {
+    /// The orbit, pan, and zoom smoothness values of this profile, in that order.
+    pub fn values(self) -> (f32, f32, f32) {
+        match self {
+            SmoothnessProfile::Instant => (0.0, 0.0, 0.0),
+            SmoothnessProfile::Snappy => (0.5, 0.3, 0.5),
+            SmoothnessProfile::Cinematic => (0.95, 0.9, 0.95),
+            SmoothnessProfile::Custom { orbit, pan, zoom } => (orbit, pan, zoom),
+        }
+    }
+}
+
+/// How a `PanOrbitCamera` moves towards its target values.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+pub enum SmoothingModel {
+    /// Exponential smoothing, controlled by `orbit.smoothness`, `pan.smoothness`, and
+    /// `zoom.smoothness`.
+    #[default]
+    Lerp,
+    /// Second-order dynamics, i.e. a damped spring, which can overshoot the target and settle
+    /// back.
+    Spring {
+        /// How fast the camera responds to changes, in Hz. Higher values are faster.
+        frequency: f32,
+        /// The damping ratio. `1.0` is critically damped, i.e. as fast as possible without
+        /// overshooting. Lower values overshoot and oscillate, higher values are sluggish.
+        damping: f32,
+        /// How the camera initially reacts to a change. `0.0` starts slowly, `1.0` reacts
+        /// immediately, values above `1.0` overshoot, and negative values anticipate the motion.
+        response: f32,
+    },
+}
+
+/// The state of a `PanOrbitCamera` that uses `SmoothingModel::Spring`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+pub struct SpringState {
+    alpha: util::Spring<f32>,
+    beta: util::Spring<f32>,
+    roll: util::Spring<f32>,
+    radius: util::Spring<f32>,
+    scale: util::Spring<f32>,
+    focus: util::Spring<Vec3>,
+}
+
+impl SpringState {
+    /// Whether any of the values is still moving
+    fn is_moving(&self) -> bool {
+        self.alpha.velocity != 0.0
+            || self.beta.velocity != 0.0
+            || self.roll.velocity != 0.0
+            || self.radius.velocity != 0.0
+            || self.scale.velocity != 0.0
+            || self.focus.velocity != Vec3::ZERO
+    }
+}
+
+/// Which clock a `PanOrbitCamera` uses for anything time-based, such as smoothing, momentum,
+/// and `PanOrbitAnimation` playback.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimeSource {
+    /// Use `Time<Real>`, so the camera keeps moving normally while the game is paused or slowed
+    /// down, e.g. in an editor or a pause menu.
+    #[default]
+    Real,
+    /// Use `Time<Virtual>`, so the camera freezes when the game is paused, and speeds up or
+    /// slows down along with it.
+    Virtual,
+    /// Use `Time`, i.e. whichever clock is the default for the schedule. In `Update`, this is the
+    /// same as `Virtual`.
+    Default,
+}
+
+impl Limits {
+    /// These limits, with the lower zoom limit filled in for a camera with the given projection.
+    /// Without a lower limit, perspective cameras can't get close enough for the near plane to
+    /// clip the focus, and no camera can zoom below `PanOrbitZoomBounds::min_zoom_distance`.
+    fn for_projection(&self, projection: &Projection, bounds: &PanOrbitZoomBounds) -> Limits {
+        let zoom_lower = self.zoom_lower.or(match projection {
+            Projection::Perspective(p) => Some(p.near * bounds.near_plane_zoom_factor),
+            Projection::Orthographic(_) => None,
+        });
+        Limits {
+            zoom_lower: Some(zoom_lower.map_or(bounds.min_zoom_distance, |lower| {
+                lower.max(bounds.min_zoom_distance)
+            })),
+            ..*self
+        }
+    }
+
+    fn zoom(&self, zoom: f32) -> f32 {
+        util::apply_limits(zoom, self.zoom_upper, self.zoom_lower)
+    }
+
+    fn alpha(&self, alpha: f32) -> f32 {
+        util::apply_limits(alpha, self.alpha_upper, self.alpha_lower)
+    }
+
+    fn beta(&self, beta: f32) -> f32 {
+        util::apply_limits(beta, self.beta_upper, self.beta_lower)
+    }
+
+    fn focus(&self, focus: Vec3) -> Vec3 {
+        Vec3::new(
+            util::apply_limits(focus.x, self.focus_upper[0], self.focus_lower[0]),
+            util::apply_limits(focus.y, self.focus_upper[1], self.focus_lower[1]),
+            util::apply_limits(focus.z, self.focus_upper[2], self.focus_lower[2]),
+        )
+    }
+}
+
+/// All the clocks a `TimeSource` can refer to
+#[derive(SystemParam)]
+pub(crate) struct Clocks<'w> {
+    time: Res<'w, Time>,
+    real: Res<'w, Time<Real>>,
+    virtual_time: Res<'w, Time<Virtual>>,
+}
+
+impl Clocks<'_> {
+    /// The time elapsed since the last frame, according to the given clock
+    pub(crate) fn delta_seconds(&self, source: TimeSource) -> f32 {
+        match source {
+            TimeSource::Real => self.real.delta_seconds(),
+            TimeSource::Virtual => self.virtual_time.delta_seconds(),
+            TimeSource::Default => self.time.delta_seconds(),
+        }
+    }
+
+    /// The time elapsed since the last frame according to the camera's clock, scaled by its
+    /// `animation_speed`
+    pub(crate) fn animation_delta_seconds(&self, pan_orbit: &PanOrbitCamera) -> f32 {
+        self.delta_seconds(pan_orbit.time_source) * pan_orbit.animation_speed.max(0.0)
+    }
+}
+
+/// Which axes panning moves the focus along.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PanMode {
+    /// Pan along the camera's right and up axes, so the scene follows the cursor.
+    #[default]
+    CameraRelative,
+    /// Pan along the world X and Z axes, regardless of the camera's yaw and pitch, so
+    /// horizontal drags move along X and vertical drags move along Z. Useful for map editors,
+    /// where the focus should stay on the ground plane.
+    WorldAligned,
+}
+
+/// What dragging with both `Bindings::button_orbit` and `Bindings::button_pan` held does.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SimultaneousDragBehavior {
+    /// Orbit, ignoring the pan button.
+    #[default]
+    PreferOrbit,
+    /// Pan, ignoring the orbit button.
+    PreferPan,
+    /// Zoom by dragging vertically, like `bindings.button_zoom`.
+    Zoom,
+    /// Orbit and pan at the same time.
+    Both,
+}
+
+/// What touchpad scrolling does without modifiers, see `Bindings::touchpad_scroll`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TouchpadScroll {
+    /// Pan the camera.
+    Pan,
+    /// Orbit the camera.
+    Orbit,
+}
+
+impl Default for TouchpadScroll {
+    fn default() -> Self {
+        match cfg!(target_os = "macos") {
+            true => TouchpadScroll::Orbit,
+            false => TouchpadScroll::Pan,
+        }
+    }
+}
+
+/// What horizontal mouse wheel scrolling does, see `Bindings::horizontal_scroll`.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum HorizontalScroll {
+    /// Pan horizontally.
+    #[default]
+    Pan,
+    /// Orbit horizontally, i.e. change alpha.
+    Orbit,
+    /// Ignore horizontal scrolling.
+    Disabled,
+}
+
+/// What the touchpad rotate (twist) gesture controls.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TouchpadRotateMode {
+    /// Orbit horizontally, i.e. change alpha.
+    #[default]
+    Alpha,
+    /// Roll the camera around the view axis.
+    Roll,
+    /// Ignore the gesture, e.g. because it is too easy to trigger accidentally while dragging.
+    Disabled,
+}
+
+/// Determines how mouse motion is converted to orbit rotation. Dragging across the full width of
+/// the reference area rotates the camera 360 degrees horizontally, and dragging across the full
+/// height rotates it 180 degrees vertically (before applying `orbit.sensitivity`).
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+pub enum MotionScale {
+    /// Normalize by the size of the window. This keeps the sensitivity reasonable for small
+    /// viewports, but means viewports of different sizes in the same window rotate at the same
+    /// speed in pixels, i.e. at different speeds relative to their size.
+    #[default]
+    Window,
+    /// Normalize by the size of the viewport. This gives a consistent feel across differently
+    /// sized panes, e.g. in an editor with several viewports in one large window.
+    Viewport,
+    /// Rotate by a fixed number of degrees per pixel of mouse movement, regardless of the size of
+    /// the window or viewport. This gives an identical feel across monitors and windows of
+    /// different sizes.
+    DegreesPerPixel(f32),
+}
+
+impl MotionScale {
+    /// The size of the area that corresponds to a full turn horizontally and a half turn
+    /// vertically, or `None` if it isn't known yet
+    fn reference_size(self, active_cam: &ActiveCameraData) -> Option<Vec2> {
+        match self {
+            MotionScale::Window => active_cam.window_size,
+            MotionScale::Viewport => active_cam.viewport_size,
+            MotionScale::DegreesPerPixel(degrees) => {
+                Some(Vec2::new(360.0, 180.0) / degrees.max(f32::EPSILON))
+            }
         }
     }
 }
@@ -271,24 +1272,246 @@ impl Default for PanOrbitCamera {
 /// `PanOrbitCameraPlugin` manages this resource automatically, in order to support multiple
 /// viewports/windows. However, if this doesn't work for you, you can take over and manage it
 /// yourself, e.g. when you want to control a camera that is rendering to a texture.
-#[derive(Resource, Default, Debug, PartialEq)]
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource, Default)]
 pub struct ActiveCameraData {
     /// ID of the entity with `PanOrbitCamera` that will handle user input. In other words, this
     /// is the camera that will move when you orbit/pan/zoom.
     pub entity: Option<Entity>,
     /// The viewport size. This is only used to scale the panning mouse motion. I recommend setting
     /// this to the actual render target dimensions (e.g. the image or viewport), and changing
-    /// `PanOrbitCamera::pan_sensitivity` to adjust the sensitivity if required.
+    /// `PanSettings::sensitivity` to adjust the sensitivity if required.
     pub viewport_size: Option<Vec2>,
     /// The size of the window. This is only used to scale the orbit mouse motion. I recommend
     /// setting this to actual dimensions of the window that you want to control the camera from,
-    /// and changing `PanOrbitCamera::orbit_sensitivity` to adjust the sensitivity if required.
+    /// and changing `OrbitSettings::sensitivity` to adjust the sensitivity if required.
     pub window_size: Option<Vec2>,
     /// Indicates to `PanOrbitCameraPlugin` that it should not update/overwrite this resource.
     /// If you are manually updating this resource you should set this to `true`.
     /// Note that setting this to `true` will effectively break multiple viewport/window support
     /// unless you manually reimplement it.
     pub manual: bool,
+    /// Whether the user is dragging with one of the active camera's mouse buttons. While they are,
+    /// the active camera doesn't change, even if the cursor moves over another viewport or window,
+    /// so the drag stays with the camera it started in. Updated automatically.
+    pub dragging: bool,
+}
+
+/// Event that can be sent to control a `PanOrbitCamera` without mutating the component
+/// directly, e.g. from gameplay code, UI buttons, or network messages.
+/// Commands are applied to the camera in `ActiveCameraData::entity`, or to all `PanOrbitCamera`s
+/// if there is no active camera. They are applied even if the camera is not `enabled`, and are
+/// subject to the same limits and smoothing as user input.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::PanOrbitCommand;
+/// fn rotate_on_click(mut commands: EventWriter<PanOrbitCommand>) {
+///     commands.send(PanOrbitCommand::Orbit(Vec2::new(0.5, 0.0)));
+/// }
+/// ```
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq)]
+pub enum PanOrbitCommand {
+    /// Orbit the camera by the given amount in radians, where `x` is added to `alpha` and `y`
+    /// is added to `beta`.
+    Orbit(Vec2),
+    /// Pan the camera by the given amount in world units, along the camera's local right (`x`)
+    /// and up (`y`) axes.
+    Pan(Vec2),
+    /// Zoom by the given amount, in the same units as a line of scroll wheel input. Positive
+    /// values zoom in, negative values zoom out.
+    Zoom(f32),
+    /// Set the target focus point.
+    SetFocus(Vec3),
+    /// Smoothly move the camera to the given focus, alpha, beta, and radius. Alpha and beta
+    /// change independently, so for large moves use `PanOrbitAnimation::fly_to` instead, which
+    /// moves along an arc around the focus.
+    FlyTo {
+        /// The new target focus point.
+        focus: Vec3,
+        /// The new target alpha value, in radians.
+        alpha: f32,
+        /// The new target beta value, in radians.
+        beta: f32,
+        /// The new target radius.
+        radius: f32,
+    },
+}
+
+/// Event that makes the next (or previous) `PanOrbitCamera` the active camera, i.e. the one that
+/// responds to input. Cameras are ordered by `Camera::order`. This lets apps without a mouse,
+/// e.g. gamepad-only or kiosk builds, choose which viewport to control.
+/// Has no effect if `ActiveCameraData::manual` is `true`.
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CycleActiveCamera {
+    /// Activate the camera with the next higher order, wrapping around to the lowest.
+    Next,
+    /// Activate the camera with the next lower order, wrapping around to the highest.
+    Previous,
+}
+
+/// Optional key bindings for cycling through cameras, equivalent to sending `CycleActiveCamera`
+/// events.
+#[derive(Resource, Reflect, Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct CycleActiveCameraKeys {
+    /// Key that activates the next camera. Defaults to `None`.
+    pub next: Option<KeyCode>,
+    /// Key that activates the previous camera. Defaults to `None`.
+    pub previous: Option<KeyCode>,
+}
+
+/// Event sent when the orbit or pan button of a `PanOrbitCamera` is pressed and released without
+/// moving further than `Bindings::drag_threshold`, i.e. a click rather than a drag. Useful
+/// for selecting objects with the same button that orbits the camera. With the default threshold
+/// of `0.0`, only presses without any mouse movement count as clicks.
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClickedWithoutDrag {
+    /// The camera that was clicked in.
+    pub camera: Entity,
+    /// The button that was clicked.
+    pub button: MouseButton,
+}
+
+/// Which kinds of input the active `PanOrbitCamera` used this frame, so that selection or gameplay
+/// systems can skip handling the same click, drag, or scroll, e.g. to avoid selecting objects
+/// while dragging to orbit. Mouse drags count as used once they move past
+/// `Bindings::drag_threshold`, so clicks are left for other systems (see
+/// `ClickedWithoutDrag`). Updated every frame in `PanOrbitCameraSubSet::ProcessMotion`, so read it
+/// after that.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{CameraInputConsumed, PanOrbitCameraSubSet};
+/// fn select_under_cursor(consumed: Res<CameraInputConsumed>) {
+///     if consumed.any() {
+///         return;
+///     }
+///     // ...
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_systems(
+///             Update,
+///             select_under_cursor.after(PanOrbitCameraSubSet::ProcessMotion),
+///         )
+///         .run();
+/// }
+/// ```
+#[derive(Resource, Reflect, Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct CameraInputConsumed {
+    /// Whether input was used to orbit the camera.
+    pub orbit: bool,
+    /// Whether input was used to pan the camera.
+    pub pan: bool,
+    /// Whether input was used to zoom the camera, including the scroll wheel.
+    pub zoom: bool,
+    /// Whether input was used to roll the camera.
+    pub roll: bool,
+}
+
+impl CameraInputConsumed {
+    /// Whether input was used for anything.
+    pub fn any(&self) -> bool {
+        self.orbit || self.pan || self.zoom || self.roll
+    }
+}
+
+/// Event sent when `ActiveCameraData::entity` changes, i.e. when the user starts interacting with
+/// a different camera, e.g. to highlight the active viewport in an editor. Also sent when the
+/// resource is changed manually.
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ActiveCameraChanged {
+    /// The previously active camera, if any.
+    pub old: Option<Entity>,
+    /// The newly active camera, if any.
+    pub new: Option<Entity>,
+}
+
+/// Event sent once when the window a `PanOrbitCamera` renders to no longer exists, e.g. because a
+/// secondary window was closed. If it was the active camera, another camera whose window still
+/// exists is activated instead, or no camera if there is none, unless `ActiveCameraData::manual`
+/// is set. Handle this event to retarget the camera to another window, or despawn it. The event is
+/// sent again if the camera loses a window after being retargeted.
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CameraTargetLost {
+    /// The camera whose window no longer exists.
+    pub camera: Entity,
+    /// The window that no longer exists, or `None` if it was the primary window.
+    pub window: Option<Entity>,
+}
+
+/// Sends `CameraTargetLost` for cameras whose window no longer exists, and moves control away from
+/// the active camera if it is one of them and `ActiveCameraData` isn't set manually
+fn handle_lost_camera_targets(
+    mut active_cam: ResMut<ActiveCameraData>,
+    mut lost_cameras: Local<Vec<Entity>>,
+    mut lost_events: EventWriter<CameraTargetLost>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    orbit_cameras: Query<(Entity, &Camera), With<PanOrbitCamera>>,
+) {
+    let lost_window = |camera: &Camera| match camera.target {
+        RenderTarget::Window(WindowRef::Primary) if primary_windows.is_empty() => Some(None),
+        RenderTarget::Window(WindowRef::Entity(window)) if !other_windows.contains(window) => {
+            Some(Some(window))
+        }
+        _ => None,
+    };
+
+    // Forget cameras that were despawned or retargeted, so they are reported again if needed
+    lost_cameras.retain(|entity| {
+        orbit_cameras
+            .get(*entity)
+            .is_ok_and(|(_, camera)| lost_window(camera).is_some())
+    });
+
+    let mut active_lost = false;
+    for (entity, camera) in orbit_cameras.iter() {
+        let Some(window) = lost_window(camera) else {
+            continue;
+        };
+        // `ActiveCameraData` that is set manually is left for the user to update
+        if active_cam.entity == Some(entity) && !active_cam.manual {
+            active_lost = true;
+        }
+        if !lost_cameras.contains(&entity) {
+            lost_cameras.push(entity);
+            lost_events.send(CameraTargetLost {
+                camera: entity,
+                window,
+            });
+        }
+    }
+
+    if active_lost {
+        // Fall back to the highest order camera that can still be controlled
+        let fallback = orbit_cameras
+            .iter()
+            .filter(|(_, camera)| lost_window(camera).is_none())
+            .max_by_key(|(_, camera)| camera.order);
+        *active_cam = match fallback {
+            Some((entity, camera)) => {
+                let window = match camera.target {
+                    RenderTarget::Window(WindowRef::Primary) => primary_windows.get_single().ok(),
+                    RenderTarget::Window(WindowRef::Entity(window)) => {
+                        other_windows.get(window).ok()
+                    }
+                    _ => None,
+                };
+                ActiveCameraData {
+                    entity: Some(entity),
+                    viewport_size: camera.logical_viewport_size(),
+                    window_size: window.map(|window| Vec2::new(window.width(), window.height())),
+                    manual: false,
+                    dragging: false,
+                }
+            }
+            None => ActiveCameraData::default(),
+        };
+    }
 }
 
 // Gathers data about the active viewport, i.e. the viewport the user is interacting with. This
@@ -298,10 +1521,35 @@ fn active_viewport_data(
     mouse_input: Res<Input<MouseButton>>,
     key_input: Res<Input<KeyCode>>,
     scroll_events: EventReader<MouseWheel>,
-    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    mut cursor_left_events: EventReader<CursorLeft>,
+    mut window_focused_events: EventReader<WindowFocused>,
+    mut cursor_window: Local<Option<Entity>>,
+    primary_windows: Query<(Entity, &Window), With<PrimaryWindow>>,
     other_windows: Query<&Window, Without<PrimaryWindow>>,
     orbit_cameras: Query<(Entity, &Camera, &PanOrbitCamera)>,
 ) {
+    // Keep track of which window the cursor is in, so that input in one window doesn't activate a
+    // camera in another window whose viewport happens to overlap numerically
+    for ev in cursor_moved_events.read() {
+        *cursor_window = Some(ev.window);
+    }
+    for ev in window_focused_events.read() {
+        if ev.focused {
+            *cursor_window = Some(ev.window);
+        }
+    }
+    for ev in cursor_left_events.read() {
+        if *cursor_window == Some(ev.window) {
+            *cursor_window = None;
+        }
+    }
+
+    // Don't hand an ongoing drag off to another camera
+    if active_cam.dragging && active_drag_pressed(&active_cam, &mouse_input, &orbit_cameras) {
+        return;
+    }
+
     let mut new_resource = ActiveCameraData::default();
     let mut max_cam_order = 0;
 
@@ -309,20 +1557,27 @@ fn active_viewport_data(
     for (entity, camera, pan_orbit) in orbit_cameras.iter() {
         let input_just_activated = util::orbit_just_pressed(pan_orbit, &mouse_input, &key_input)
             || util::pan_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || util::zoom_just_pressed(pan_orbit, &mouse_input, &key_input)
+            || util::roll_just_pressed(pan_orbit, &mouse_input)
             || !scroll_events.is_empty();
 
         if input_just_activated {
             has_input = true;
             // First check if cursor is in the same window as this camera
             if let RenderTarget::Window(win_ref) = camera.target {
-                let window = match win_ref {
-                    WindowRef::Primary => primary_windows
-                        .get_single()
-                        .expect("Must exist, since the camera is referencing it"),
+                // The window may have been closed, in which case the camera can't be activated
+                let Some((window_entity, window)) = (match win_ref {
+                    WindowRef::Primary => primary_windows.get_single().ok(),
                     WindowRef::Entity(entity) => other_windows
                         .get(entity)
-                        .expect("Must exist, since the camera is referencing it"),
+                        .ok()
+                        .map(|window| (entity, window)),
+                }) else {
+                    continue;
                 };
+                if cursor_window.is_some_and(|cursor_window| cursor_window != window_entity) {
+                    continue;
+                }
                 if let Some(cursor_pos) = window.cursor_position() {
                     // Now check if cursor is within this camera's viewport
                     if let Some(Rect { min, max }) = camera.logical_viewport_rect() {
@@ -341,6 +1596,7 @@ fn active_viewport_data(
                                 viewport_size: camera.logical_viewport_size(),
                                 window_size: Some(Vec2::new(window.width(), window.height())),
                                 manual: false,
+                                dragging: false,
                             };
                             max_cam_order = camera.order;
                         }
@@ -355,162 +1611,554 @@ fn active_viewport_data(
     }
 }
 
-/// Main system for processing input and converting to transformations
-fn pan_orbit_camera(
+/// Sends `ActiveCameraChanged` when the active camera differs from the last frame
+fn send_active_camera_changed(
     active_cam: Res<ActiveCameraData>,
-    mouse_input: Res<Input<MouseButton>>,
-    key_input: Res<Input<KeyCode>>,
-    mut mouse_motion: EventReader<MouseMotion>,
-    mut scroll_events: EventReader<MouseWheel>,
-    mut zoom_events: EventReader<TouchpadMagnify>,
-    mut rotate_events: EventReader<TouchpadRotate>,
-    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera, &mut Transform, &mut Projection)>,
-    #[cfg(feature = "bevy_egui")] mut contexts: bevy_egui::EguiContexts,
-    #[cfg(feature = "bevy_egui")] windows: Query<Entity, With<Window>>,
+    mut last_active: Local<Option<Entity>>,
+    mut changed_events: EventWriter<ActiveCameraChanged>,
 ) {
-    #[allow(unused_mut)]
-    let mut pointer_over_egui = false;
-    #[cfg(feature = "bevy_egui")]
-    {
-        for window in windows.iter() {
-            let ctx = contexts.ctx_for_window_mut(window);
-            if ctx.is_pointer_over_area() {
-                pointer_over_egui = true;
-                break;
-            }
-        }
+    if active_cam.entity != *last_active {
+        changed_events.send(ActiveCameraChanged {
+            old: *last_active,
+            new: active_cam.entity,
+        });
+        *last_active = active_cam.entity;
     }
+}
 
-    let mouse_delta = mouse_motion.read().map(|event| event.delta).sum::<Vec2>();
-
-    for (entity, mut pan_orbit, mut transform, mut projection) in orbit_cameras.iter_mut() {
-        // Closures that apply limits to the alpha, beta, and zoom values
-        let apply_zoom_limits = {
-            let zoom_upper_limit = pan_orbit.zoom_upper_limit;
-            let zoom_lower_limit = pan_orbit.zoom_lower_limit;
-            move |zoom: f32| util::apply_limits(zoom, zoom_upper_limit, zoom_lower_limit).max(0.05)
-        };
+/// Updates the sizes in `ActiveCameraData` when a window is resized or its scale factor changes,
+/// e.g. when it is dragged to a monitor with a different DPI, so the sensitivity stays the same
+/// even in the middle of a drag
+fn refresh_active_camera_sizes(
+    mut active_cam: ResMut<ActiveCameraData>,
+    mut resized_events: EventReader<WindowResized>,
+    mut scale_factor_events: EventReader<WindowScaleFactorChanged>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    orbit_cameras: Query<&Camera, With<PanOrbitCamera>>,
+) {
+    // Read both, so neither keeps old events around
+    let resized = resized_events.read().count() > 0;
+    let scale_factor_changed = scale_factor_events.read().count() > 0;
+    if !resized && !scale_factor_changed {
+        return;
+    }
+    let Some(camera) = active_cam
+        .entity
+        .and_then(|entity| orbit_cameras.get(entity).ok())
+    else {
+        return;
+    };
+    let window = match camera.target {
+        RenderTarget::Window(WindowRef::Primary) => primary_windows.get_single().ok(),
+        RenderTarget::Window(WindowRef::Entity(window)) => other_windows.get(window).ok(),
+        _ => None,
+    };
+    // Cameras that render to textures have their sizes set when they're activated
+    let Some(window) = window else {
+        return;
+    };
+    let viewport_size = Some(util::logical_viewport_size(camera, window));
+    let window_size = Some(Vec2::new(window.width(), window.height()));
+    if active_cam.viewport_size != viewport_size || active_cam.window_size != window_size {
+        active_cam.viewport_size = viewport_size;
+        active_cam.window_size = window_size;
+    }
+}
 
-        let apply_alpha_limits = {
-            let alpha_upper_limit = pan_orbit.alpha_upper_limit;
-            let alpha_lower_limit = pan_orbit.alpha_lower_limit;
-            move |alpha: f32| util::apply_limits(alpha, alpha_upper_limit, alpha_lower_limit)
-        };
+/// Changes the active camera in response to `CycleActiveCamera` events and key presses
+fn cycle_active_camera(
+    mut active_cam: ResMut<ActiveCameraData>,
+    cycle_keys: Res<CycleActiveCameraKeys>,
+    key_input: Res<Input<KeyCode>>,
+    mut cycle_events: EventReader<CycleActiveCamera>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    orbit_cameras: Query<(Entity, &Camera), With<PanOrbitCamera>>,
+) {
+    let mut requests: Vec<CycleActiveCamera> = cycle_events.read().copied().collect();
+    if cycle_keys
+        .next
+        .is_some_and(|key| key_input.just_pressed(key))
+    {
+        requests.push(CycleActiveCamera::Next);
+    }
+    if cycle_keys
+        .previous
+        .is_some_and(|key| key_input.just_pressed(key))
+    {
+        requests.push(CycleActiveCamera::Previous);
+    }
+    if requests.is_empty() {
+        return;
+    }
 
-        let apply_beta_limits = {
-            let beta_upper_limit = pan_orbit.beta_upper_limit;
-            let beta_lower_limit = pan_orbit.beta_lower_limit;
-            move |beta: f32| util::apply_limits(beta, beta_upper_limit, beta_lower_limit)
-        };
+    let mut cameras: Vec<(Entity, &Camera)> = orbit_cameras.iter().collect();
+    // Sort by entity as well, so the order is stable for cameras with the same order
+    cameras.sort_by_key(|(entity, camera)| (camera.order, *entity));
+    let entities: Vec<Entity> = cameras.iter().map(|(entity, _)| *entity).collect();
 
-        let apply_focus_limits = {
-            let focus_x_upper_limit = pan_orbit.focus_x_upper_limit;
-            let focus_x_lower_limit = pan_orbit.focus_x_lower_limit;
-            let focus_y_upper_limit = pan_orbit.focus_y_upper_limit;
-            let focus_y_lower_limit = pan_orbit.focus_y_lower_limit;
-            let focus_z_upper_limit = pan_orbit.focus_z_upper_limit;
-            let focus_z_lower_limit = pan_orbit.focus_z_lower_limit;
-            move |focus: Vec3| {
-                let mut new_focus = focus;
-                new_focus.x =
-                    util::apply_limits(new_focus.x, focus_x_upper_limit, focus_x_lower_limit);
-                new_focus.y =
-                    util::apply_limits(new_focus.y, focus_y_upper_limit, focus_y_lower_limit);
-                new_focus.z =
-                    util::apply_limits(new_focus.z, focus_z_upper_limit, focus_z_lower_limit);
-                new_focus
-            }
-        };
+    let mut entity = active_cam.entity;
+    for request in requests {
+        entity = util::cycle_entity(&entities, entity, request == CycleActiveCamera::Previous);
+    }
+    let Some((entity, camera)) = cameras.into_iter().find(|(e, _)| Some(*e) == entity) else {
+        return;
+    };
 
-        if !pan_orbit.initialized {
-            // Calculate alpha, beta, and radius from the camera's position. If user sets all
-            // these explicitly, this calculation is wasted, but that's okay since it will only run
-            // once on init.
-            let (alpha, beta, radius) =
-                util::calculate_from_translation_and_focus(transform.translation, pan_orbit.focus);
-            let &mut mut alpha = pan_orbit.alpha.get_or_insert(alpha);
-            let &mut mut beta = pan_orbit.beta.get_or_insert(beta);
-            let &mut mut radius = pan_orbit.radius.get_or_insert(radius);
-
-            // Apply limits
-            alpha = apply_alpha_limits(alpha);
-            beta = apply_beta_limits(beta);
-            radius = apply_zoom_limits(radius);
-
-            // Set initial values
-            pan_orbit.alpha = Some(alpha);
-            pan_orbit.beta = Some(beta);
-            pan_orbit.radius = Some(radius);
-            pan_orbit.target_alpha = alpha;
-            pan_orbit.target_beta = beta;
-            pan_orbit.target_radius = radius;
-            pan_orbit.target_focus = pan_orbit.focus;
-
-            if let Projection::Orthographic(ref mut p) = *projection {
-                // If user hasn't set initial scale value, we want to initialize it with the
-                // projection's scale, otherwise we want to override the projection's scale with
-                // the value the user provided.
-                if pan_orbit.scale.is_none() {
-                    pan_orbit.scale = Some(p.scale);
-                }
-                p.scale = apply_zoom_limits(pan_orbit.scale.expect("Just set to Some above"));
-                pan_orbit.target_scale = p.scale;
+    let window_size = match camera.target {
+        RenderTarget::Window(WindowRef::Primary) => primary_windows.get_single().ok(),
+        RenderTarget::Window(WindowRef::Entity(window)) => other_windows.get(window).ok(),
+        _ => None,
+    }
+    .map(|window| Vec2::new(window.width(), window.height()));
+
+    active_cam.set_if_neq(ActiveCameraData {
+        entity: Some(entity),
+        viewport_size: camera.logical_viewport_size(),
+        window_size,
+        manual: false,
+        dragging: false,
+    });
+}
+
+/// Whether any of the active camera's drag buttons are held
+pub(crate) fn active_drag_pressed(
+    active_cam: &ActiveCameraData,
+    mouse_input: &Input<MouseButton>,
+    orbit_cameras: &Query<(Entity, &Camera, &PanOrbitCamera)>,
+) -> bool {
+    active_cam
+        .entity
+        .and_then(|entity| orbit_cameras.get(entity).ok())
+        .is_some_and(|(_, _, pan_orbit)| util::drag_button_pressed(pan_orbit, mouse_input))
+}
+
+/// Records whether a drag is in progress on the active camera, once it has been chosen for this
+/// frame
+fn track_active_drag(
+    mut active_cam: ResMut<ActiveCameraData>,
+    mouse_input: Res<Input<MouseButton>>,
+    orbit_cameras: Query<(Entity, &Camera, &PanOrbitCamera)>,
+) {
+    let dragging = active_drag_pressed(&active_cam, &mouse_input, &orbit_cameras);
+    // Avoid triggering change detection if nothing changed
+    if active_cam.dragging != dragging {
+        active_cam.dragging = dragging;
+    }
+}
+
+/// Scales the near/far planes of perspective projections with the radius, for cameras that opt in
+fn update_clip_planes(mut orbit_cameras: Query<(&PanOrbitCamera, &mut Projection)>) {
+    for (pan_orbit, mut projection) in orbit_cameras.iter_mut() {
+        let Some(radius) = pan_orbit.radius else {
+            continue;
+        };
+        let Projection::Perspective(ref p) = *projection else {
+            continue;
+        };
+        let near = pan_orbit
+            .near_plane_ratio
+            .map_or(p.near, |ratio| radius * ratio);
+        let far = pan_orbit
+            .far_plane_ratio
+            .map_or(p.far, |ratio| radius * ratio);
+        // Avoid triggering change detection if nothing changed
+        if near != p.near || far != p.far {
+            if let Projection::Perspective(ref mut p) = *projection {
+                p.near = near;
+                p.far = far;
             }
+        }
+    }
+}
+
+/// Calculates the initial alpha, beta, radius, and scale of new cameras, or cameras whose
+/// `initialized` was set back to `false`, and moves them to their initial position. This runs
+/// in `PreUpdate`, so the values are valid before any user systems in `Update` query them, and
+/// again before the camera is processed, for cameras spawned earlier in the same frame.
+pub(crate) fn initialize_pan_orbit_cameras(
+    zoom_bounds: Res<PanOrbitZoomBounds>,
+    mut orbit_cameras: Query<
+        (&mut PanOrbitCamera, &mut Transform, &mut Projection),
+        Changed<PanOrbitCamera>,
+    >,
+) {
+    for (mut pan_orbit, mut transform, mut projection) in orbit_cameras.iter_mut() {
+        if pan_orbit.initialized {
+            continue;
+        }
+        let limits = pan_orbit.limits.for_projection(&projection, &zoom_bounds);
+        let apply_zoom_limits = |zoom: f32| limits.zoom(zoom);
+
+        // Calculate alpha, beta, and radius from the camera's position. If user sets all
+        // these explicitly, this calculation is wasted, but that's okay since it will only run
+        // once on init.
+        // Alpha and beta are relative to the orientation offset
+        let focus = pan_orbit.focus;
+        let translation =
+            focus + pan_orbit.orientation_offset.inverse() * (transform.translation - focus);
+        let (alpha, beta, radius) = util::calculate_from_translation_and_focus(translation, focus);
+        let &mut mut alpha = pan_orbit.alpha.get_or_insert(alpha);
+        let &mut mut beta = pan_orbit.beta.get_or_insert(beta);
+        let &mut mut radius = pan_orbit.radius.get_or_insert(radius);
 
-            util::update_orbit_transform(alpha, beta, radius, pan_orbit.focus, &mut transform);
+        // Apply limits
+        alpha = limits.alpha(alpha);
+        beta = limits.beta(beta);
+        radius = apply_zoom_limits(radius);
 
-            pan_orbit.initialized = true;
+        // Set initial values
+        pan_orbit.alpha = Some(alpha);
+        pan_orbit.beta = Some(beta);
+        pan_orbit.radius = Some(radius);
+        pan_orbit.target_alpha = alpha;
+        pan_orbit.target_beta = beta;
+        pan_orbit.target_radius = radius;
+        pan_orbit.target_focus = pan_orbit.focus;
+
+        if let Projection::Orthographic(ref p) = *projection {
+            // If user hasn't set initial scale value, we want to initialize it with the
+            // projection's scale, otherwise we want to override the projection's scale with
+            // the value the user provided.
+            let scale = apply_zoom_limits(*pan_orbit.scale.get_or_insert(p.scale));
+            util::set_orthographic_scale(&mut projection, scale);
+            pan_orbit.target_scale = scale;
+        }
+
+        util::update_orbit_transform(
+            alpha,
+            beta,
+            pan_orbit.roll,
+            radius,
+            pan_orbit.focus,
+            pan_orbit.focus_offset,
+            pan_orbit.orientation_offset,
+            &mut transform,
+        );
+
+        pan_orbit.initialized = true;
+    }
+}
+
+/// How many pixels of touchpad scrolling one line of mouse wheel scrolling is equivalent to
+const SCROLL_LINE_PIXELS: f32 = 20.0;
+
+/// Main system for processing input and converting to transformations
+pub(crate) fn pan_orbit_camera(
+    clocks: Clocks,
+    zoom_bounds: Res<PanOrbitZoomBounds>,
+    active_cam: Res<ActiveCameraData>,
+    mouse_input: Res<Input<MouseButton>>,
+    key_input: Res<Input<KeyCode>>,
+    mut input_buffer: ResMut<input::InputBuffer>,
+    mut command_events: EventReader<PanOrbitCommand>,
+    mut click_events: EventWriter<ClickedWithoutDrag>,
+    mut input_consumed: ResMut<CameraInputConsumed>,
+    mut orbit_cameras: Query<(
+        Entity,
+        &Camera,
+        &mut PanOrbitCamera,
+        &Transform,
+        &Projection,
+    )>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    aabbs: Query<(&Aabb, &GlobalTransform)>,
+    #[cfg(feature = "bevy_egui")] mut egui_pointer: egui_viewport::EguiPointer,
+    #[cfg(feature = "bevy_mod_picking")] picking_state: Res<picking::PickingDragState>,
+    #[cfg(feature = "pivot_handle")] pivot_handle_state: Res<pivot_handle::PivotHandleDragState>,
+) {
+    #[cfg(feature = "bevy_egui")]
+    let egui_windows = egui_pointer.windows_with_pointer();
+
+    let input = input_buffer.take();
+    let mouse_delta = input.mouse_delta;
+    // Drags that started over a pickable entity are handled by picking instead
+    #[cfg(feature = "bevy_mod_picking")]
+    let mouse_delta = match picking_state.blocked {
+        true => Vec2::ZERO,
+        false => mouse_delta,
+    };
+    // Drags of a pivot handle move the focus directly instead
+    #[cfg(feature = "pivot_handle")]
+    let mouse_delta = match pivot_handle_state.dragging {
+        true => Vec2::ZERO,
+        false => mouse_delta,
+    };
+    let commands: Vec<PanOrbitCommand> = command_events.read().copied().collect();
+    let mut consumed = CameraInputConsumed::default();
+
+    for (entity, camera, mut pan_orbit, transform, projection) in orbit_cameras.iter_mut() {
+        // Read this before making any changes, so only changes made elsewhere are detected
+        let changed = pan_orbit.is_changed();
+
+        #[cfg(not(feature = "bevy_egui"))]
+        let pointer_over_egui = false;
+        #[cfg(feature = "bevy_egui")]
+        let pointer_over_egui = !pan_orbit.ignore_egui
+            && egui_pointer.blocks_camera(
+                &egui_windows,
+                entity,
+                camera,
+                &primary_windows,
+                &other_windows,
+            );
+
+        // Closures that apply limits to the alpha, beta, and zoom values
+        let limits = pan_orbit.limits.for_projection(projection, &zoom_bounds);
+        let apply_zoom_limits = |zoom: f32| limits.zoom(zoom);
+        let apply_alpha_limits = |alpha: f32| limits.alpha(alpha);
+        let apply_beta_limits = |beta: f32| limits.beta(beta);
+        let apply_focus_limits = |focus: Vec3| limits.focus(focus);
+
+        if pan_orbit.auto_frame_on_init {
+            if let Some((min, max)) = util::combined_bounds(aabbs.iter()) {
+                let center = (min + max) / 2.0;
+                let sphere_radius = ((max - min) / 2.0).length();
+                let radius = match *projection {
+                    Projection::Perspective(ref p) => {
+                        let direction = util::orbit_forward(
+                            pan_orbit.target_alpha,
+                            pan_orbit.target_beta,
+                            pan_orbit.orientation_offset,
+                        );
+                        // Leave a 10% margin around the scene
+                        let (_, _, radius) =
+                            util::fit_sphere(sphere_radius, direction, 0.1, p.fov, p.aspect_ratio);
+                        radius
+                    }
+                    // Only the distance needs to be far enough to not clip the scene
+                    Projection::Orthographic(_) => sphere_radius * 2.0,
+                };
+                let radius = apply_zoom_limits(radius);
+                pan_orbit.focus = center;
+                pan_orbit.target_focus = center;
+                pan_orbit.radius = Some(radius);
+                pan_orbit.target_radius = radius;
+                pan_orbit.auto_frame_on_init = false;
+                pan_orbit.force_update = true;
+            }
         }
 
         // 1 - Get Input
 
         let mut pan = Vec2::ZERO;
         let mut rotation_move = Vec2::ZERO;
+        let mut roll_move = 0.0;
         let mut scroll_line = 0.0;
         let mut scroll_pixel = 0.0;
+        let mut pinched = false;
+        let mut focus_move = 0.0;
         let mut orbit_button_changed = false;
+        let mut is_dragging = false;
+        let mut is_orbiting = false;
+        let mut is_panning = false;
+        let mut is_zooming = false;
+        let mut snap = false;
+        let mut click_button = pan_orbit.drag_state.click_button;
+        let mut click_distance = pan_orbit.drag_state.click_distance;
 
         // The reason we only skip getting input if the camera is inactive/disabled is because
         // it might still be moving (lerping towards target values) when the user is not
         // actively controlling it.
         if !pointer_over_egui && pan_orbit.enabled && active_cam.entity == Some(entity) {
-            if util::orbit_pressed(&pan_orbit, &mouse_input, &key_input) {
-                rotation_move += mouse_delta * pan_orbit.orbit_sensitivity;
-            } else if util::pan_pressed(&pan_orbit, &mouse_input, &key_input) {
-                // Pan only if we're not rotating at the moment
-                pan += mouse_delta * pan_orbit.pan_sensitivity;
-            }
-
-            for ev in scroll_events.read() {
-                match ev.unit {
-                    MouseScrollUnit::Line => {
-                        let direction = match pan_orbit.reversed_zoom {
-                            true => -1.0,
-                            false => 1.0,
-                        };
-
-                        scroll_line += ev.y * direction * pan_orbit.zoom_sensitivity;
+            // Ignore mouse movement until it passes the drag threshold, and detect clicks
+            let mut mouse_delta = mouse_delta;
+            let camera_buttons = [
+                Some(pan_orbit.bindings.button_orbit),
+                Some(pan_orbit.bindings.button_pan),
+                pan_orbit.bindings.button_zoom,
+                pan_orbit.bindings.button_roll,
+            ];
+            if let Some(button) = camera_buttons
+                .into_iter()
+                .flatten()
+                .find(|button| mouse_input.just_pressed(*button))
+            {
+                click_button = Some(button);
+                click_distance = 0.0;
+                if pan_orbit.pan.stop_on_press {
+                    pan_orbit.pan_velocity = Vec3::ZERO;
+                }
+            }
+            if let Some(button) = click_button {
+                if mouse_input.just_released(button) {
+                    // Zoom and roll drags are rarely shared with selection, so only orbit and pan
+                    // buttons send clicks
+                    if button == pan_orbit.bindings.button_orbit
+                        || button == pan_orbit.bindings.button_pan
+                    {
+                        click_events.send(ClickedWithoutDrag {
+                            camera: entity,
+                            button,
+                        });
                     }
-                    MouseScrollUnit::Pixel => {
-                        let orbit = pan_orbit
-                            .modifier_orbit_touchpad
-                            .is_some_and(|modifier| key_input.pressed(modifier));
-
-                        if orbit {
-                            rotation_move += Vec2::new(ev.x, ev.y) * pan_orbit.orbit_sensitivity;
-                        } else {
-                            pan += Vec2::new(ev.x, ev.y) * pan_orbit.pan_sensitivity;
-                        }
+                    click_button = None;
+                } else {
+                    click_distance += mouse_delta.length();
+                    if click_distance > pan_orbit.bindings.drag_threshold {
+                        click_button = None;
+                    } else {
+                        mouse_delta = Vec2::ZERO;
                     }
+                }
+            }
+
+            let orbit_held = util::orbit_pressed(&pan_orbit, &mouse_input, &key_input);
+            let pan_held = util::pan_pressed(&pan_orbit, &mouse_input, &key_input);
+            let (orbit_drag, pan_drag, both_zoom) =
+                match (orbit_held && pan_held, pan_orbit.bindings.simultaneous_drag) {
+                    (false, _) => (orbit_held, pan_held, false),
+                    (true, SimultaneousDragBehavior::PreferOrbit) => (true, false, false),
+                    (true, SimultaneousDragBehavior::PreferPan) => (false, true, false),
+                    (true, SimultaneousDragBehavior::Zoom) => (false, false, true),
+                    (true, SimultaneousDragBehavior::Both) => (true, true, false),
                 };
+            let zoom_drag = both_zoom
+                || (!orbit_held
+                    && !pan_held
+                    && !util::roll_pressed(&pan_orbit, &mouse_input)
+                    && util::zoom_pressed(&pan_orbit, &mouse_input, &key_input));
+
+            if orbit_drag {
+                rotation_move += mouse_delta * pan_orbit.orbit.sensitivity;
+            }
+            if pan_drag {
+                pan += mouse_delta * pan_orbit.pan.sensitivity;
+            }
+            if !orbit_held && !pan_held && util::roll_pressed(&pan_orbit, &mouse_input) {
+                roll_move += mouse_delta.x * pan_orbit.orbit.roll_sensitivity;
+            }
+            if zoom_drag {
+                // Dragging the full height of the window zooms the same amount as 5 lines of
+                // scroll wheel input
+                if let Some(win_size) = active_cam.window_size {
+                    let direction = match pan_orbit.zoom.reversed {
+                        true => -1.0,
+                        false => 1.0,
+                    };
+                    scroll_line +=
+                        -mouse_delta.y / win_size.y * 5.0 * direction * pan_orbit.zoom.sensitivity;
+                }
             }
 
-            for ev in zoom_events.read() {
-                scroll_pixel += ev.0 * pan_orbit.zoom_sensitivity * 2.;
+            if input.scroll_line.y != 0.0 {
+                let held = |modifier: Option<KeyCode>| {
+                    modifier.is_some_and(|modifier| key_input.pressed(modifier))
+                };
+                // Treat each line like a touchpad scroll of a fixed number of pixels
+                let delta = input.scroll_line.y * SCROLL_LINE_PIXELS;
+                if held(pan_orbit.bindings.modifier_wheel_pan_vertical) {
+                    pan += Vec2::new(0.0, delta) * pan_orbit.pan.sensitivity;
+                } else if held(pan_orbit.bindings.modifier_wheel_pan_horizontal) {
+                    pan += Vec2::new(delta, 0.0) * pan_orbit.pan.sensitivity;
+                } else if held(pan_orbit.bindings.modifier_wheel_orbit) {
+                    rotation_move += Vec2::new(delta, 0.0) * pan_orbit.orbit.sensitivity;
+                } else {
+                    let direction = match pan_orbit.zoom.reversed {
+                        true => -1.0,
+                        false => 1.0,
+                    };
+
+                    scroll_line += input.scroll_line.y * direction * pan_orbit.zoom.sensitivity;
+                }
             }
+            if input.scroll_line.x != 0.0 {
+                // Treat each line like a touchpad scroll of a fixed number of pixels
+                let delta = Vec2::new(input.scroll_line.x * SCROLL_LINE_PIXELS, 0.0);
+                match pan_orbit.bindings.horizontal_scroll {
+                    HorizontalScroll::Pan => pan += delta * pan_orbit.pan.sensitivity,
+                    HorizontalScroll::Orbit => {
+                        rotation_move += delta * pan_orbit.orbit.sensitivity;
+                    }
+                    HorizontalScroll::Disabled => {}
+                }
+            }
+            if input.scroll_pixel != Vec2::ZERO {
+                let held = |modifier: Option<KeyCode>| {
+                    modifier.is_some_and(|modifier| key_input.pressed(modifier))
+                };
+                let mode = if held(pan_orbit.bindings.modifier_pan_touchpad) {
+                    TouchpadScroll::Pan
+                } else if held(pan_orbit.bindings.modifier_orbit_touchpad) {
+                    TouchpadScroll::Orbit
+                } else {
+                    pan_orbit.bindings.touchpad_scroll
+                };
 
-            for ev in rotate_events.read() {
-                rotation_move.x += ev.0 * pan_orbit.orbit_sensitivity * 3.;
+                match mode {
+                    TouchpadScroll::Orbit => {
+                        rotation_move += input.scroll_pixel * pan_orbit.orbit.sensitivity;
+                    }
+                    TouchpadScroll::Pan => pan += input.scroll_pixel * pan_orbit.pan.sensitivity,
+                }
+            }
+
+            scroll_pixel += input.magnify * pan_orbit.zoom.sensitivity * 2.;
+            pinched = input.magnify != 0.0;
+
+            let key_zoom_step =
+                pan_orbit.zoom.key_speed * clocks.delta_seconds(pan_orbit.time_source);
+            if pan_orbit
+                .bindings
+                .key_zoom_in
+                .is_some_and(|key| key_input.pressed(key))
+            {
+                scroll_line += key_zoom_step;
+            }
+            if pan_orbit
+                .bindings
+                .key_zoom_out
+                .is_some_and(|key| key_input.pressed(key))
+            {
+                scroll_line -= key_zoom_step;
+            }
+
+            let key_move_step = pan_orbit.pan.key_move_speed
+                * pan_orbit.target_radius
+                * clocks.delta_seconds(pan_orbit.time_source);
+            if pan_orbit
+                .bindings
+                .key_move_forward
+                .is_some_and(|key| key_input.pressed(key))
+            {
+                focus_move += key_move_step;
+            }
+            if pan_orbit
+                .bindings
+                .key_move_back
+                .is_some_and(|key| key_input.pressed(key))
+            {
+                focus_move -= key_move_step;
+            }
+
+            if let Some(margin) = pan_orbit.pan.edge_pan_margin {
+                let cursor_pos =
+                    util::cursor_viewport_position(camera, &primary_windows, &other_windows);
+                if let (Some(cursor_pos), Some(vp_size)) = (cursor_pos, active_cam.viewport_size) {
+                    if !util::orbit_pressed(&pan_orbit, &mouse_input, &key_input) {
+                        let direction = util::edge_pan_direction(cursor_pos, vp_size, margin);
+                        // Move the view towards the edge, which is the opposite of dragging
+                        // towards it
+                        pan -= direction
+                            * pan_orbit.pan.edge_pan_speed
+                            * clocks.delta_seconds(pan_orbit.time_source);
+                    }
+                }
+            }
+
+            if input.rotate != 0.0 {
+                let amount = input.rotate * pan_orbit.orbit.touchpad_rotate_multiplier;
+                match pan_orbit.bindings.touchpad_rotate_mode {
+                    TouchpadRotateMode::Alpha => {
+                        rotation_move.x += amount * pan_orbit.orbit.sensitivity;
+                    }
+                    TouchpadRotateMode::Roll => {
+                        roll_move += amount * pan_orbit.orbit.roll_sensitivity
+                    }
+                    TouchpadRotateMode::Disabled => {}
+                }
             }
 
             if util::orbit_just_pressed(&pan_orbit, &mouse_input, &key_input)
@@ -518,74 +2166,365 @@ fn pan_orbit_camera(
             {
                 orbit_button_changed = true;
             }
+
+            snap = util::orbit_just_released(&pan_orbit, &mouse_input, &key_input)
+                && pan_orbit
+                    .bindings
+                    .modifier_snap
+                    .is_none_or(|modifier| key_input.pressed(modifier));
+
+            // Discard the input for interactions that are disabled
+            if !pan_orbit.orbit.enabled {
+                rotation_move = Vec2::ZERO;
+                roll_move = 0.0;
+                snap = false;
+            }
+            if !pan_orbit.pan.enabled {
+                pan = Vec2::ZERO;
+                focus_move = 0.0;
+            }
+            if !pan_orbit.zoom.enabled {
+                scroll_line = 0.0;
+                scroll_pixel = 0.0;
+            }
+
+            is_orbiting = orbit_drag && pan_orbit.orbit.enabled;
+            is_panning = pan_drag && pan_orbit.pan.enabled;
+            is_zooming =
+                (zoom_drag && pan_orbit.zoom.enabled) || scroll_line != 0.0 || scroll_pixel != 0.0;
+            is_dragging =
+                orbit_held || pan_held || util::zoom_pressed(&pan_orbit, &mouse_input, &key_input);
+
+            // Drags that haven't passed the threshold yet may still turn out to be clicks
+            let past_threshold = click_button.is_none();
+            consumed = CameraInputConsumed {
+                orbit: rotation_move != Vec2::ZERO || (is_orbiting && past_threshold),
+                pan: pan != Vec2::ZERO || (is_panning && past_threshold),
+                zoom: scroll_line != 0.0
+                    || scroll_pixel != 0.0
+                    || (zoom_drag && pan_orbit.zoom.enabled && past_threshold),
+                roll: roll_move != 0.0,
+            };
+
+            if pan_orbit
+                .bindings
+                .modifier_precision
+                .is_some_and(|modifier| key_input.pressed(modifier))
+            {
+                rotation_move *= pan_orbit.bindings.precision_multiplier;
+                roll_move *= pan_orbit.bindings.precision_multiplier;
+                pan *= pan_orbit.bindings.precision_multiplier;
+                scroll_line *= pan_orbit.bindings.precision_multiplier;
+                scroll_pixel *= pan_orbit.bindings.precision_multiplier;
+            }
+        }
+
+        let mut has_moved = false;
+        if active_cam.entity.is_none_or(|active| active == entity) {
+            for command in commands.iter() {
+                match *command {
+                    PanOrbitCommand::Orbit(delta) => {
+                        let target = pan_orbit.target_orbit_state().apply_orbit(delta);
+                        pan_orbit.set_target_orbit_state(target);
+                    }
+                    PanOrbitCommand::Pan(delta) => {
+                        let (right, up) = pan_orbit.pan_axes(transform);
+                        let target = pan_orbit
+                            .target_orbit_state()
+                            .apply_pan_along(delta, right, up);
+                        pan_orbit.set_target_orbit_state(target);
+                    }
+                    PanOrbitCommand::Zoom(delta) => {
+                        scroll_line += delta;
+                    }
+                    PanOrbitCommand::SetFocus(focus) => {
+                        pan_orbit.target_focus = focus;
+                    }
+                    PanOrbitCommand::FlyTo {
+                        focus,
+                        alpha,
+                        beta,
+                        radius,
+                    } => {
+                        pan_orbit.target_focus = focus;
+                        pan_orbit.target_alpha = alpha;
+                        pan_orbit.target_beta = beta;
+                        pan_orbit.target_radius = radius;
+                    }
+                }
+                has_moved = true;
+            }
+        }
+
+        // Skip cameras that are at rest and have nothing to do, so idle cameras don't do any
+        // unnecessary work or trigger change detection
+        let has_input = rotation_move != Vec2::ZERO
+            || pan != Vec2::ZERO
+            || roll_move != 0.0
+            || scroll_line != 0.0
+            || scroll_pixel != 0.0
+            || is_dragging
+            || orbit_button_changed
+            || snap
+            || focus_move != 0.0
+            || has_moved;
+        if pan_orbit.at_rest
+            && !changed
+            && !has_input
+            && pan_orbit.zoom_velocity == 0.0
+            && pan_orbit.pan_velocity == Vec3::ZERO
+            && pan_orbit.drag_state.is_idle()
+        {
+            continue;
         }
 
         // 2 - Process input into target alpha/beta, or focus, radius
 
-        if orbit_button_changed {
-            // Only check for upside down when orbiting started or ended this frame,
-            // so we don't reverse the alpha direction while the user is still dragging
-            let wrapped_beta = (pan_orbit.target_beta % TAU).abs();
-            pan_orbit.is_upside_down = wrapped_beta > TAU / 4.0 && wrapped_beta < 3.0 * TAU / 4.0;
+        if focus_move != 0.0 {
+            pan_orbit.move_focus_forward(focus_move);
+            has_moved = true;
+        }
+
+        // While dragging with elasticity enabled, limits are applied softly, and the targets are
+        // only clamped to the limits once the drag ends
+        let elastic = pan_orbit.limits.elasticity > 0.0 && is_dragging;
+
+        if !is_orbiting {
+            // Don't check for upside down while the user is orbiting, so we don't reverse the
+            // alpha direction in the middle of a drag
+            let is_upside_down = util::update_upside_down(
+                pan_orbit.is_upside_down,
+                pan_orbit.target_beta,
+                pan_orbit.orbit.upside_down_hysteresis,
+            );
+            if pan_orbit.is_upside_down != is_upside_down {
+                pan_orbit.is_upside_down = is_upside_down;
+            }
+        }
+
+        if roll_move != 0.0 {
+            let motion_size = pan_orbit.orbit.motion_scale.reference_size(&active_cam);
+            if let Some(size) = motion_size {
+                // Dragging across the full width rolls the camera a full turn
+                pan_orbit.target_roll -= roll_move / size.x * TAU;
+                has_moved = true;
+            }
         }
 
-        let mut has_moved = false;
         if rotation_move.length_squared() > 0.0 {
-            // Use window size for rotation otherwise the sensitivity
+            // Use window size for rotation by default, otherwise the sensitivity
             // is far too high for small viewports
-            if let Some(win_size) = active_cam.window_size {
+            let motion_size = pan_orbit.orbit.motion_scale.reference_size(&active_cam);
+            if let Some(size) = motion_size {
                 let delta_x = {
-                    let delta = rotation_move.x / win_size.x * PI * 2.0;
-                    if pan_orbit.is_upside_down {
+                    let delta = rotation_move.x / size.x * PI * 2.0;
+                    if pan_orbit.is_upside_down != pan_orbit.orbit.invert_x {
+                        -delta
+                    } else {
+                        delta
+                    }
+                };
+                let delta_y = {
+                    let delta = rotation_move.y / size.y * PI;
+                    if pan_orbit.orbit.invert_y {
                         -delta
                     } else {
                         delta
                     }
                 };
-                let delta_y = rotation_move.y / win_size.y * PI;
-                pan_orbit.target_alpha -= delta_x;
-                pan_orbit.target_beta += delta_y;
+                if pan_orbit.orbit.velocity_mode && is_orbiting {
+                    // Dragging changes the speed of rotation rather than the angle itself, so
+                    // that the further you drag, the faster the camera rotates
+                    let max_speed = pan_orbit.orbit.max_velocity;
+                    pan_orbit.orbit_velocity = (pan_orbit.orbit_velocity
+                        + Vec2::new(delta_x, delta_y))
+                    .clamp_length_max(max_speed);
+                } else {
+                    let delta = pan_orbit.unlocked_orbit(Vec2::new(-delta_x, delta_y));
+                    let target = pan_orbit.target_orbit_state().apply_orbit(delta);
+                    pan_orbit.set_target_orbit_state(target);
 
-                has_moved = true;
+                    has_moved = true;
+                }
             }
-        } else if pan.length_squared() > 0.0 {
+        }
+        if pan.length_squared() > 0.0 {
             // Make panning distance independent of resolution and FOV,
             if let Some(vp_size) = active_cam.viewport_size {
-                let mut multiplier = 1.0;
-                match *projection {
-                    Projection::Perspective(ref p) => {
-                        pan *= Vec2::new(p.fov * p.aspect_ratio, p.fov) / vp_size;
-                        // Make panning proportional to distance away from focus point
-                        if let Some(radius) = pan_orbit.radius {
-                            multiplier = radius;
-                        }
-                    }
-                    Projection::Orthographic(ref p) => {
-                        pan *= Vec2::new(p.area.width(), p.area.height()) / vp_size;
+                // Make panning proportional to distance away from focus point
+                let radius = pan_orbit.radius.unwrap_or(1.0);
+                let world_pan = util::pixels_to_world_pan(pan, projection, vp_size, radius);
+                pan = Vec2::new(world_pan.x, -world_pan.y);
+
+                // Lock the pan directions within the bounded box, unless we're allowed to
+                // overshoot it
+                let lock_focus = |focus: Vec3| {
+                    if elastic {
+                        focus
+                    } else {
+                        apply_focus_limits(focus)
                     }
-                }
+                };
+                let (right_axis, up_axis) = pan_orbit.pan_axes(transform);
+                let right = (lock_focus(pan_orbit.target_focus + right_axis * pan.x.signum())
+                    - pan_orbit.target_focus)
+                    .normalize_or_zero();
 
-                // Lock the pan directions within the bounded box
-                let right = (apply_focus_limits(
-                    pan_orbit.target_focus + transform.right() * pan.x.signum(),
-                ) - pan_orbit.target_focus)
+                let up = (lock_focus(pan_orbit.target_focus + up_axis * pan.y.signum())
+                    - pan_orbit.target_focus)
                     .normalize_or_zero();
 
-                let up =
-                    (apply_focus_limits(pan_orbit.target_focus + transform.up() * pan.y.signum())
-                        - pan_orbit.target_focus)
-                        .normalize_or_zero();
+                // Translate by the pan axes
+                let target = pan_orbit.target_orbit_state();
+                let panned =
+                    target.apply_pan_along(Vec2::new(-pan.x.abs(), pan.y.abs()), right, up);
+                let translation = panned.focus - target.focus;
+                pan_orbit.set_target_orbit_state(panned);
+                // Use the time the input was gathered over, like the zoom momentum
+                if is_panning && input.duration > 0.0 {
+                    pan_orbit.pan_velocity = translation / input.duration;
+                }
+                has_moved = true;
+            }
+        }
 
-                // Translate by local axes
-                let right = right * -pan.x * pan.x.signum();
-                let up = up * pan.y * pan.y.signum();
-                let translation = (right + up) * multiplier;
-                pan_orbit.target_focus += translation;
+        if let Some(friction) = pan_orbit.pan.kinetic_friction {
+            if is_panning {
+                // Holding the view still before releasing it shouldn't throw it
+                if pan == Vec2::ZERO {
+                    pan_orbit.pan_velocity = Vec3::ZERO;
+                }
+            } else if pan_orbit.pan_velocity != Vec3::ZERO {
+                // Keep moving after the drag ended, slowing down over time
+                let dt = clocks.delta_seconds(pan_orbit.time_source);
+                let velocity = pan_orbit.pan_velocity;
+                pan_orbit.target_focus += velocity * dt;
+                pan_orbit.pan_velocity *= (-friction * dt).exp();
+                // Stop once it's moving less than 1% of the view per second
+                let zoom = match *projection {
+                    Projection::Orthographic(_) => pan_orbit.target_scale,
+                    Projection::Perspective(_) => pan_orbit.target_radius,
+                };
+                if pan_orbit.pan_velocity.length() < zoom * 0.01 {
+                    pan_orbit.pan_velocity = Vec3::ZERO;
+                }
                 has_moved = true;
             }
+        } else {
+            pan_orbit.pan_velocity = Vec3::ZERO;
+        }
+
+        if pan_orbit.orbit.velocity_mode && is_orbiting {
+            let dt = clocks.delta_seconds(pan_orbit.time_source);
+            let velocity = pan_orbit.orbit_velocity;
+            let delta = pan_orbit.unlocked_orbit(Vec2::new(-velocity.x, velocity.y) * dt);
+            let target = pan_orbit.target_orbit_state().apply_orbit(delta);
+            pan_orbit.set_target_orbit_state(target);
+            has_moved = true;
+        } else {
+            pan_orbit.orbit_velocity = Vec2::ZERO;
         }
 
-        if (scroll_line + scroll_pixel).abs() > 0.0 {
+        // Pixel-based zoom, as a fraction of the current zoom value
+        let mut pixel_zoom_fraction = -scroll_pixel * 0.2;
+        if let Some(friction) = pan_orbit.zoom.kinetic_friction {
+            let dt = clocks.delta_seconds(pan_orbit.time_source);
+            if scroll_pixel != 0.0 {
+                // Use the time the gesture input was gathered over, rather than the frame time,
+                // so the speed is right even if input was buffered across several frames
+                if input.duration > 0.0 {
+                    pan_orbit.zoom_velocity = pixel_zoom_fraction / input.duration;
+                }
+            } else if pan_orbit.zoom_velocity != 0.0 {
+                // Keep zooming after the gesture ended, slowing down over time
+                pixel_zoom_fraction = pan_orbit.zoom_velocity * dt;
+                pan_orbit.zoom_velocity *= (-friction * dt).exp();
+                if pan_orbit.zoom_velocity.abs() < 0.01 {
+                    pan_orbit.zoom_velocity = 0.0;
+                }
+            }
+        } else {
+            pan_orbit.zoom_velocity = 0.0;
+        }
+
+        if scroll_line.abs() > 0.0 || pixel_zoom_fraction.abs() > 0.0 {
+            if pan_orbit.zoom.auto_depth && active_cam.entity == Some(entity) {
+                if let (Projection::Perspective(_), Some(radius)) = (projection, pan_orbit.radius) {
+                    let hit =
+                        util::cursor_viewport_position(camera, &primary_windows, &other_windows)
+                            .and_then(|cursor_pos| {
+                                camera.viewport_to_world(
+                                    &GlobalTransform::from(*transform),
+                                    cursor_pos,
+                                )
+                            })
+                            .and_then(|ray| {
+                                util::raycast_aabbs(ray.origin, ray.direction, aabbs.iter())
+                            });
+                    if let Some(hit) = hit {
+                        // Move the focus along the view direction only, so the camera itself
+                        // doesn't move or rotate. The depth is measured from where the camera
+                        // would be without `focus_offset`, since that's where the radius starts.
+                        let origin =
+                            transform.translation - transform.rotation * pan_orbit.focus_offset;
+                        let depth = (hit - origin).dot(transform.forward());
+                        if depth > 0.0 {
+                            let offset = depth - radius;
+                            let focus_offset = transform.forward() * offset;
+                            pan_orbit.focus += focus_offset;
+                            pan_orbit.target_focus += focus_offset;
+                            pan_orbit.radius = Some(depth);
+                            pan_orbit.target_radius += offset;
+                        }
+                    }
+                }
+            }
+
+            // For zooming towards the cursor, find the offset from the focus to the point under the
+            // cursor, per unit of orthographic scale, or of radius for perspective cameras
+            let mut cursor_offset = None;
+            let pinch_to_cursor = pan_orbit.zoom.pinch_to_cursor && pinched;
+            if (pan_orbit.zoom.to_cursor || pinch_to_cursor) && active_cam.entity == Some(entity) {
+                let cursor_pos =
+                    util::cursor_viewport_position(camera, &primary_windows, &other_windows);
+                match *projection {
+                    Projection::Orthographic(ref p) => {
+                        cursor_offset = cursor_pos.zip(camera.logical_viewport_size()).map(
+                            |(cursor_pos, vp_size)| {
+                                let fraction = cursor_pos / vp_size;
+                                let view_pos = Vec2::new(
+                                    p.area.min.x + fraction.x * p.area.width(),
+                                    p.area.max.y - fraction.y * p.area.height(),
+                                );
+                                (transform.right() * view_pos.x + transform.up() * view_pos.y)
+                                    / p.scale
+                            },
+                        );
+                    }
+                    Projection::Perspective(_) if pinch_to_cursor => {
+                        let focus = pan_orbit.focus;
+                        cursor_offset = cursor_pos
+                            .and_then(|cursor_pos| {
+                                camera.viewport_to_world(
+                                    &GlobalTransform::from(*transform),
+                                    cursor_pos,
+                                )
+                            })
+                            .and_then(|ray| {
+                                util::ray_plane_intersection(
+                                    ray.origin,
+                                    ray.direction,
+                                    focus,
+                                    transform.forward(),
+                                )
+                            })
+                            .zip(pan_orbit.radius)
+                            .map(|(hit, radius)| (hit - focus) / radius);
+                    }
+                    Projection::Perspective(_) => {}
+                }
+            }
+
             // Choose different reference values based on the current projection
             let pan_orbit = &mut *pan_orbit;
             let (target_value, value) = if let Projection::Orthographic(_) = *projection {
@@ -593,10 +2532,17 @@ fn pan_orbit_camera(
             } else {
                 (&mut pan_orbit.target_radius, &mut pan_orbit.radius)
             };
+            let old_target_value = *target_value;
 
             // Calculate the impact of scrolling on the reference value
-            let line_delta = -scroll_line * (*target_value) * 0.2;
-            let pixel_delta = -scroll_pixel * (*target_value) * 0.2;
+            let line_delta = match pan_orbit.zoom.step_distance {
+                Some(distance) => -scroll_line * distance,
+                None => {
+                    OrbitState::zoom_value(*target_value, scroll_line * pan_orbit.zoom.step)
+                        - *target_value
+                }
+            };
+            let pixel_delta = pixel_zoom_fraction * (*target_value);
 
             // Update the target value
             *target_value += line_delta + pixel_delta;
@@ -604,84 +2550,633 @@ fn pan_orbit_camera(
             // If it is pixel-based scrolling, add it directly to the current value
             *value = value.map(|value| apply_zoom_limits(value + pixel_delta));
 
+            // Move the focus so the point under the cursor stays in the same place on screen
+            if let Some(offset) = cursor_offset {
+                let new_target_value = apply_zoom_limits(*target_value);
+                pan_orbit.target_focus += offset * (old_target_value - new_target_value);
+            }
+
             has_moved = true;
         }
 
+        if snap {
+            if let Some(increment) = pan_orbit.orbit.alpha_snap {
+                pan_orbit.target_alpha = util::snap_to_increment(pan_orbit.target_alpha, increment);
+            }
+            if let Some(increment) = pan_orbit.orbit.beta_snap {
+                pan_orbit.target_beta = util::snap_to_increment(pan_orbit.target_beta, increment);
+            }
+        }
+
         // 3 - Apply constraints
 
-        pan_orbit.target_alpha = apply_alpha_limits(pan_orbit.target_alpha);
-        pan_orbit.target_beta = apply_beta_limits(pan_orbit.target_beta);
-        pan_orbit.target_radius = apply_zoom_limits(pan_orbit.target_radius);
-        pan_orbit.target_scale = apply_zoom_limits(pan_orbit.target_scale);
-        pan_orbit.target_focus = apply_focus_limits(pan_orbit.target_focus);
+        if !elastic {
+            pan_orbit.target_alpha = apply_alpha_limits(pan_orbit.target_alpha);
+            pan_orbit.target_beta = apply_beta_limits(pan_orbit.target_beta);
+            pan_orbit.target_radius = apply_zoom_limits(pan_orbit.target_radius);
+            pan_orbit.target_scale = apply_zoom_limits(pan_orbit.target_scale);
+            pan_orbit.target_focus = apply_focus_limits(pan_orbit.target_focus);
+        }
 
-        if !pan_orbit.allow_upside_down {
+        if !pan_orbit.orbit.allow_upside_down {
+            let max_beta = PI / 2.0 - pan_orbit.limits.beta_pole_padding;
             pan_orbit.target_beta =
-                util::apply_limits(pan_orbit.target_beta, Some(PI / 2.0), Some(-PI / 2.0));
+                util::apply_limits(pan_orbit.target_beta, Some(max_beta), Some(-max_beta));
         }
 
-        // 4 - Update the camera's transform based on current values
+        if let Some(ground) = pan_orbit.limits.stay_above {
+            let min_beta = util::min_beta_above_ground(
+                ground,
+                pan_orbit.target_focus.y,
+                pan_orbit.target_radius,
+            );
+            if pan_orbit.target_beta < min_beta {
+                pan_orbit.target_beta = min_beta;
+            }
+        }
 
-        if let (Some(alpha), Some(beta), Some(radius)) =
-            (pan_orbit.alpha, pan_orbit.beta, pan_orbit.radius)
-        {
-            if has_moved
-                || pan_orbit.target_alpha != alpha
-                || pan_orbit.target_beta != beta
-                || pan_orbit.target_radius != radius
-                || pan_orbit.target_focus != pan_orbit.focus
-                // Unlike the rest, scale will always be None for non-orthographic cameras,
-                // so we can't include in the if let above
-                || Some(pan_orbit.target_scale) != pan_orbit.scale
-                || pan_orbit.force_update
+        let drag_state = DragState {
+            orbiting: is_orbiting,
+            panning: is_panning,
+            zooming: is_zooming,
+            dragging: is_dragging,
+            click_button,
+            click_distance,
+            ..pan_orbit.drag_state
+        };
+        // Avoid triggering change detection while the camera is idle
+        if pan_orbit.drag_state != drag_state {
+            pan_orbit.drag_state = drag_state;
+        }
+
+        if has_moved {
+            pan_orbit.force_update = true;
+        }
+    }
+
+    input_consumed.set_if_neq(consumed);
+}
+
+/// The longest frame time used for smoothing, in seconds
+const MAX_SMOOTHING_DELTA_SECONDS: f32 = 0.1;
+
+/// Moves each `PanOrbitCamera` towards its target values, and updates its transform and
+/// projection accordingly
+pub(crate) fn apply_pan_orbit_transforms(
+    clocks: Clocks,
+    zoom_bounds: Res<PanOrbitZoomBounds>,
+    mut orbit_cameras: Query<(
+        &mut PanOrbitCamera,
+        &mut Transform,
+        &mut Projection,
+        Option<&mut PanOrbitVelocity>,
+    )>,
+) {
+    // Each camera is independent, so they can be processed in parallel, which helps when there
+    // are many cameras
+    orbit_cameras.par_iter_mut().for_each(
+        |(mut pan_orbit, mut transform, mut projection, orbit_velocity)| {
+            if pan_orbit.at_rest && !pan_orbit.is_changed() {
+                if let Some(mut orbit_velocity) = orbit_velocity {
+                    orbit_velocity.set_if_neq(PanOrbitVelocity::default());
+                }
+                return;
+            }
+
+            let limits = pan_orbit.limits.for_projection(&projection, &zoom_bounds);
+            let apply_zoom_limits = |zoom: f32| limits.zoom(zoom);
+            let apply_alpha_limits = |alpha: f32| limits.alpha(alpha);
+            let apply_beta_limits = |beta: f32| limits.beta(beta);
+            let apply_focus_limits = |focus: Vec3| limits.focus(focus);
+
+            // While dragging with elasticity enabled, limits are applied softly, and the targets are
+            // only clamped to the limits once the drag ends
+            let elastic = pan_orbit.limits.elasticity > 0.0 && pan_orbit.drag_state.dragging;
+
+            // The values to actually move towards. These only differ from the targets while
+            // overshooting the limits during an elastic drag.
+            let (target_alpha, target_beta, target_radius, target_scale, target_focus) = if elastic
             {
-                // Interpolate towards the target values
-                let new_alpha = util::lerp_and_snap_f32(
-                    alpha,
+                let elasticity = pan_orbit.limits.elasticity;
+                let soften = |value: f32, limited: f32| limited + (value - limited) * elasticity;
+                let focus = pan_orbit.target_focus;
+                let limited_focus = apply_focus_limits(focus);
+                (
+                    soften(
+                        pan_orbit.target_alpha,
+                        apply_alpha_limits(pan_orbit.target_alpha),
+                    ),
+                    soften(
+                        pan_orbit.target_beta,
+                        apply_beta_limits(pan_orbit.target_beta),
+                    ),
+                    soften(
+                        pan_orbit.target_radius,
+                        apply_zoom_limits(pan_orbit.target_radius),
+                    )
+                    .max(zoom_bounds.min_zoom_distance),
+                    soften(
+                        pan_orbit.target_scale,
+                        apply_zoom_limits(pan_orbit.target_scale),
+                    )
+                    .max(zoom_bounds.min_zoom_distance),
+                    Vec3::new(
+                        soften(focus.x, limited_focus.x),
+                        soften(focus.y, limited_focus.y),
+                        soften(focus.z, limited_focus.z),
+                    ),
+                )
+            } else {
+                (
                     pan_orbit.target_alpha,
-                    pan_orbit.orbit_smoothness,
-                );
-                let new_beta = util::lerp_and_snap_f32(
-                    beta,
                     pan_orbit.target_beta,
-                    pan_orbit.orbit_smoothness,
-                );
-                let new_radius = util::lerp_and_snap_f32(
-                    radius,
                     pan_orbit.target_radius,
-                    pan_orbit.zoom_smoothness,
-                );
-                let new_scale = util::lerp_and_snap_f32(
-                    pan_orbit.scale.unwrap_or(pan_orbit.target_scale),
                     pan_orbit.target_scale,
-                    pan_orbit.zoom_smoothness,
-                );
-                let new_focus = util::lerp_and_snap_vec3(
-                    pan_orbit.focus,
                     pan_orbit.target_focus,
-                    pan_orbit.pan_smoothness,
-                );
+                )
+            };
 
-                if let Projection::Orthographic(ref mut p) = *projection {
-                    p.scale = new_scale;
+            if let Some(profile) = pan_orbit.smoothness_transition {
+                // Move each value towards the profile at a constant rate, taking a quarter of a
+                // second to go all the way from 0.0 to 1.0
+                let max_step = clocks.animation_delta_seconds(&pan_orbit) * 4.0;
+                let (orbit, pan, zoom) = profile.values();
+                pan_orbit.orbit.smoothness =
+                    util::move_towards(pan_orbit.orbit.smoothness, orbit, max_step);
+                pan_orbit.pan.smoothness =
+                    util::move_towards(pan_orbit.pan.smoothness, pan, max_step);
+                pan_orbit.zoom.smoothness =
+                    util::move_towards(pan_orbit.zoom.smoothness, zoom, max_step);
+                if (
+                    pan_orbit.orbit.smoothness,
+                    pan_orbit.pan.smoothness,
+                    pan_orbit.zoom.smoothness,
+                ) == (orbit, pan, zoom)
+                {
+                    pan_orbit.smoothness_transition = None;
                 }
+            }
 
-                util::update_orbit_transform(
-                    new_alpha,
-                    new_beta,
-                    new_radius,
-                    new_focus,
-                    &mut transform,
-                );
+            // 4 - Update the camera's transform based on current values
 
-                // Update the current values
-                pan_orbit.alpha = Some(new_alpha);
-                pan_orbit.beta = Some(new_beta);
-                pan_orbit.radius = Some(new_radius);
-                pan_orbit.scale = Some(new_scale);
-                pan_orbit.focus = new_focus;
-                pan_orbit.force_update = false;
+            let old_translation = transform.translation;
+            let old_state = pan_orbit.orbit_state();
+            let old_roll = pan_orbit.roll;
+            let mut at_rest = pan_orbit.initialized && pan_orbit.smoothness_transition.is_none();
+
+            if let (Some(alpha), Some(beta), Some(radius)) =
+                (pan_orbit.alpha, pan_orbit.beta, pan_orbit.radius)
+            {
+                if target_alpha != alpha
+                || target_beta != beta
+                || target_radius != radius
+                || target_focus != pan_orbit.focus
+                // Unlike the rest, scale will always be None for non-orthographic cameras,
+                // so we can't include in the if let above
+                || Some(target_scale) != pan_orbit.scale
+                || pan_orbit.target_roll != pan_orbit.roll
+                || pan_orbit.force_update
+                || pan_orbit.spring_state.is_moving()
+                {
+                    at_rest = false;
+                    // Limit the time step, so after a long frame the camera still eases towards
+                    // targets that received a lot of buffered input, rather than jumping there
+                    let dt = clocks
+                        .delta_seconds(pan_orbit.time_source)
+                        .min(MAX_SMOOTHING_DELTA_SECONDS)
+                        * pan_orbit.animation_speed.max(0.0);
+                    let scale = pan_orbit.scale.unwrap_or(target_scale);
+                    let (target_roll, roll) = (pan_orbit.target_roll, pan_orbit.roll);
+                    let snap_threshold = pan_orbit.snap_threshold;
+                    let (new_alpha, new_beta, new_roll, new_radius, new_scale, new_focus) =
+                        match pan_orbit.smoothing_model {
+                            SmoothingModel::Lerp => {
+                                // Interpolate towards the target values, with each group's own
+                                // smoothness
+                                let state = OrbitState::new(pan_orbit.focus, alpha, beta, radius);
+                                let target = OrbitState::new(
+                                    target_focus,
+                                    target_alpha,
+                                    target_beta,
+                                    target_radius,
+                                );
+                                let step = |smoothness: f32| {
+                                    state.step_smoothing(&target, smoothness, snap_threshold, dt)
+                                };
+                                let orbited = step(pan_orbit.orbit.smoothness);
+                                let panned = step(pan_orbit.pan.smoothness);
+                                let zoomed = step(pan_orbit.zoom.smoothness);
+
+                                // Roll and the orthographic scale aren't part of `OrbitState`, so
+                                // they are interpolated the same way here. Adjust smoothness for
+                                // the frame time, so it doesn't depend on frame rate
+                                let orbit_smoothness =
+                                    util::frame_smoothness(pan_orbit.orbit.smoothness, dt);
+                                let zoom_smoothness =
+                                    util::frame_smoothness(pan_orbit.zoom.smoothness, dt);
+                                (
+                                    orbited.alpha,
+                                    orbited.beta,
+                                    util::lerp_and_snap_f32(
+                                        roll,
+                                        target_roll,
+                                        orbit_smoothness,
+                                        snap_threshold,
+                                    ),
+                                    zoomed.radius,
+                                    util::lerp_and_snap_f32(
+                                        scale,
+                                        target_scale,
+                                        zoom_smoothness,
+                                        snap_threshold,
+                                    ),
+                                    panned.focus,
+                                )
+                            }
+                            SmoothingModel::Spring {
+                                frequency,
+                                damping,
+                                response,
+                            } => {
+                                let step =
+                                    |value: f32, target: f32, spring: &mut util::Spring<f32>| {
+                                        util::spring_step(
+                                            value,
+                                            target,
+                                            spring,
+                                            frequency,
+                                            damping,
+                                            response,
+                                            snap_threshold,
+                                            dt,
+                                        )
+                                    };
+                                let focus = pan_orbit.focus;
+                                let state = &mut pan_orbit.spring_state;
+                                (
+                                    step(alpha, target_alpha, &mut state.alpha),
+                                    step(beta, target_beta, &mut state.beta),
+                                    step(roll, target_roll, &mut state.roll),
+                                    step(radius, target_radius, &mut state.radius),
+                                    step(scale, target_scale, &mut state.scale),
+                                    util::spring_step(
+                                        focus,
+                                        target_focus,
+                                        &mut state.focus,
+                                        frequency,
+                                        damping,
+                                        response,
+                                        snap_threshold,
+                                        dt,
+                                    ),
+                                )
+                            }
+                        };
+
+                    // Cap the speeds after smoothing, so they also apply to large target jumps
+                    let (new_alpha, new_beta, new_roll) = match pan_orbit.orbit.max_speed {
+                        Some(max_speed) => (
+                            util::move_towards(alpha, new_alpha, max_speed * dt),
+                            util::move_towards(beta, new_beta, max_speed * dt),
+                            util::move_towards(roll, new_roll, max_speed * dt),
+                        ),
+                        None => (new_alpha, new_beta, new_roll),
+                    };
+                    let new_focus = match pan_orbit.pan.max_speed {
+                        Some(max_speed) => {
+                            util::move_towards_vec3(pan_orbit.focus, new_focus, max_speed * dt)
+                        }
+                        None => new_focus,
+                    };
+                    let (new_radius, new_scale) = match pan_orbit.zoom.max_speed {
+                        Some(max_speed) => (
+                            util::move_towards(radius, new_radius, radius * max_speed * dt),
+                            util::move_towards(scale, new_scale, scale * max_speed * dt),
+                        ),
+                        None => (new_radius, new_scale),
+                    };
+
+                    util::set_orthographic_scale(&mut projection, new_scale);
+
+                    let mut new_transform = *transform;
+                    util::update_orbit_transform(
+                        new_alpha,
+                        new_beta,
+                        new_roll,
+                        new_radius,
+                        new_focus,
+                        pan_orbit.focus_offset,
+                        pan_orbit.orientation_offset,
+                        &mut new_transform,
+                    );
+                    // Avoid triggering change detection for changes too small to matter
+                    if pan_orbit.force_update
+                        || util::transform_differs(
+                            &transform,
+                            &new_transform,
+                            pan_orbit.transform_epsilon,
+                        )
+                    {
+                        *transform = new_transform;
+                    }
+
+                    // Update the current values
+                    pan_orbit.alpha = Some(new_alpha);
+                    pan_orbit.beta = Some(new_beta);
+                    pan_orbit.roll = new_roll;
+                    pan_orbit.radius = Some(new_radius);
+                    pan_orbit.scale = Some(new_scale);
+                    pan_orbit.focus = new_focus;
+                    pan_orbit.force_update = false;
+                }
             }
-        }
+
+            let dt = clocks.delta_seconds(pan_orbit.time_source);
+            let velocity = if dt > 0.0 {
+                (transform.translation - old_translation) / dt
+            } else {
+                Vec3::ZERO
+            };
+            // Avoid triggering change detection while the camera is idle
+            if pan_orbit.drag_state.velocity != velocity {
+                pan_orbit.drag_state.velocity = velocity;
+            }
+            if let Some(mut orbit_velocity) = orbit_velocity {
+                orbit_velocity.set_if_neq(PanOrbitVelocity::between(
+                    old_state,
+                    pan_orbit.orbit_state(),
+                    pan_orbit.roll - old_roll,
+                    transform.translation - old_translation,
+                    dt,
+                ));
+            }
+
+            // 5 - Normalize alpha, shifting the target by the same amount so any ongoing motion
+            // continues unaffected
+
+            if pan_orbit.orbit.normalize_alpha
+                && pan_orbit.limits.alpha_upper.is_none()
+                && pan_orbit.limits.alpha_lower.is_none()
+            {
+                if let Some(alpha) = pan_orbit.alpha {
+                    let offset = (alpha / TAU).floor() * TAU;
+                    if offset != 0.0 {
+                        pan_orbit.alpha = Some(alpha - offset);
+                        pan_orbit.target_alpha -= offset;
+                    }
+                }
+            }
+
+            if pan_orbit.at_rest != at_rest {
+                pan_orbit.at_rest = at_rest;
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod degree_helpers_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn swaps_reversed_limits() {
+        let mut pan_orbit = PanOrbitCamera::default();
+        pan_orbit.set_beta_limits_degrees(45.0, -10.0);
+        assert!(approx_eq!(
+            f32,
+            pan_orbit.limits.beta_lower.unwrap(),
+            -10.0_f32.to_radians()
+        ));
+        assert!(approx_eq!(
+            f32,
+            pan_orbit.limits.beta_upper.unwrap(),
+            45.0_f32.to_radians()
+        ));
+    }
+
+    #[test]
+    fn round_trips_angles() {
+        let mut pan_orbit = PanOrbitCamera::default();
+        pan_orbit.set_alpha_degrees(90.0);
+        pan_orbit.set_beta_degrees(-30.0);
+        assert!(approx_eq!(
+            f32,
+            pan_orbit.yaw_degrees(),
+            90.0,
+            epsilon = 1e-4
+        ));
+        assert!(approx_eq!(
+            f32,
+            pan_orbit.pitch_degrees(),
+            -30.0,
+            epsilon = 1e-4
+        ));
+    }
+}
+
+#[cfg(test)]
+mod from_yaw_pitch_radius_tests {
+    use super::*;
+
+    #[test]
+    fn transform_looks_at_focus_from_radius() {
+        let focus = Vec3::new(1.0, 2.0, 3.0);
+        let camera = PanOrbitCamera::from_yaw_pitch_radius(0.0, 0.0, 5.0, focus);
+        assert_eq!(camera.target_radius, 5.0);
+        let transform = camera.transform();
+        assert!(transform
+            .translation
+            .abs_diff_eq(focus + Vec3::Z * 5.0, 1e-5));
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_Z, 1e-5));
+    }
+}
+
+#[cfg(test)]
+mod set_immediate_tests {
+    use super::*;
+
+    #[test]
+    fn sets_current_and_target_values() {
+        let mut camera = PanOrbitCamera {
+            target_alpha: 1.0,
+            zoom_velocity: 3.0,
+            ..default()
+        };
+        camera.set_immediate(0.5, 0.25, 8.0, Vec3::X);
+        assert_eq!(camera.alpha, Some(0.5));
+        assert_eq!(camera.target_alpha, 0.5);
+        assert_eq!(camera.beta, Some(0.25));
+        assert_eq!(camera.target_beta, 0.25);
+        assert_eq!(camera.radius, Some(8.0));
+        assert_eq!(camera.target_radius, 8.0);
+        assert_eq!(camera.focus, Vec3::X);
+        assert_eq!(camera.target_focus, Vec3::X);
+        assert_eq!(camera.zoom_velocity, 0.0);
+        assert!(camera.force_update);
+    }
+
+    #[test]
+    fn single_value_leaves_others_smoothed() {
+        let mut camera = PanOrbitCamera {
+            alpha: Some(0.0),
+            target_alpha: 1.0,
+            ..default()
+        };
+        camera.set_radius_immediate(4.0);
+        assert_eq!(camera.radius, Some(4.0));
+        assert_eq!(camera.target_radius, 4.0);
+        assert_eq!(camera.alpha, Some(0.0));
+        assert_eq!(camera.target_alpha, 1.0);
+        assert!(camera.force_update);
+    }
+}
+
+#[cfg(test)]
+mod limits_tests {
+    use super::*;
+
+    #[test]
+    fn zoom_lower_limit_defaults_to_near_plane() {
+        let zoom_bounds = PanOrbitZoomBounds::default();
+        let perspective = Projection::Perspective(PerspectiveProjection {
+            near: 0.5,
+            ..default()
+        });
+        let limits = Limits::default().for_projection(&perspective, &zoom_bounds);
+        assert_eq!(limits.zoom(0.1), 1.0);
+
+        let limits = Limits {
+            zoom_lower: Some(0.2),
+            ..default()
+        };
+        let limits = limits.for_projection(&perspective, &zoom_bounds);
+        assert_eq!(limits.zoom(0.1), 0.2);
+
+        let orthographic = Projection::Orthographic(OrthographicProjection::default());
+        let limits = Limits::default().for_projection(&orthographic, &zoom_bounds);
+        assert_eq!(limits.zoom(0.0), zoom_bounds.min_zoom_distance);
+    }
+}
+
+#[cfg(test)]
+mod pan_orbit_camera_tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    /// Creates a world with everything `pan_orbit_camera` needs, and the given camera as the
+    /// active camera
+    fn controller_world(pan_orbit: PanOrbitCamera) -> (World, Entity) {
+        let mut world = World::new();
+        let mut real_time = Time::<Real>::default();
+        real_time.update();
+        real_time.update_with_duration(Duration::from_millis(100));
+        world.insert_resource(real_time);
+        world.init_resource::<Time>();
+        world.init_resource::<Time<Virtual>>();
+        world.init_resource::<PanOrbitZoomBounds>();
+        world.init_resource::<CameraInputConsumed>();
+        world.init_resource::<Events<PanOrbitCommand>>();
+        world.init_resource::<Events<ClickedWithoutDrag>>();
+        world.init_resource::<Input<MouseButton>>();
+        world.init_resource::<Input<KeyCode>>();
+        world.init_resource::<input::InputBuffer>();
+        #[cfg(feature = "bevy_egui")]
+        world.init_resource::<bevy_egui::EguiUserTextures>();
+        #[cfg(feature = "bevy_mod_picking")]
+        world.init_resource::<picking::PickingDragState>();
+        #[cfg(feature = "pivot_handle")]
+        world.init_resource::<pivot_handle::PivotHandleDragState>();
+
+        let camera = world
+            .spawn((
+                Camera::default(),
+                Transform::default(),
+                Projection::default(),
+                PanOrbitCamera {
+                    initialized: true,
+                    ..pan_orbit
+                },
+            ))
+            .id();
+        world.insert_resource(ActiveCameraData {
+            entity: Some(camera),
+            viewport_size: Some(Vec2::new(800.0, 600.0)),
+            window_size: Some(Vec2::new(800.0, 600.0)),
+            manual: true,
+            ..default()
+        });
+        (world, camera)
+    }
+
+    /// Runs the controller for one frame with the move forward key held and a touchpad rotate
+    /// gesture, and returns the camera afterwards
+    fn run_controller(orbit_enabled: bool, pan_enabled: bool) -> PanOrbitCamera {
+        let (mut world, camera) = controller_world(PanOrbitCamera {
+            orbit: OrbitSettings {
+                enabled: orbit_enabled,
+                ..default()
+            },
+            pan: PanSettings {
+                enabled: pan_enabled,
+                ..default()
+            },
+            bindings: Bindings {
+                key_move_forward: Some(KeyCode::W),
+                touchpad_rotate_mode: TouchpadRotateMode::Roll,
+                ..default()
+            },
+            ..default()
+        });
+        world.resource_mut::<Input<KeyCode>>().press(KeyCode::W);
+        world.resource_mut::<input::InputBuffer>().rotate = 1.0;
+        world.run_system_once(pan_orbit_camera);
+        *world.get::<PanOrbitCamera>(camera).unwrap()
+    }
+
+    /// Presses and releases `button` without moving the mouse, and returns the clicks sent
+    fn click(button: MouseButton) -> Vec<ClickedWithoutDrag> {
+        let (mut world, _) = controller_world(PanOrbitCamera {
+            bindings: Bindings {
+                button_zoom: Some(MouseButton::Middle),
+                ..default()
+            },
+            ..default()
+        });
+        world.resource_mut::<Input<MouseButton>>().press(button);
+        world.run_system_once(pan_orbit_camera);
+        let mut mouse_input = world.resource_mut::<Input<MouseButton>>();
+        mouse_input.clear();
+        mouse_input.release(button);
+        world.run_system_once(pan_orbit_camera);
+        let events = world.resource::<Events<ClickedWithoutDrag>>();
+        events.get_reader().read(events).copied().collect()
+    }
+
+    #[test]
+    fn disabled_interactions_leave_targets_unchanged() {
+        let camera = run_controller(false, false);
+        assert_eq!(camera.target_focus, Vec3::ZERO);
+        assert_eq!(camera.target_roll, 0.0);
+    }
+
+    #[test]
+    fn enabled_interactions_change_targets() {
+        let camera = run_controller(true, true);
+        assert_ne!(camera.target_focus, Vec3::ZERO);
+        assert_ne!(camera.target_roll, 0.0);
+    }
+
+    #[test]
+    fn zero_threshold_clicks_with_orbit_button() {
+        let clicks = click(MouseButton::Left);
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].button, MouseButton::Left);
+    }
+
+    #[test]
+    fn zero_threshold_ignores_zoom_button() {
+        assert!(click(MouseButton::Middle).is_empty());
     }
 }
@@ -0,0 +1,160 @@
+use crate::{util, PanOrbitCamera};
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::primitives::Aabb;
+use std::f32::consts::{PI, TAU};
+
+/// Add this to an entity with `PanOrbitCamera` to orbit around a point by long-pressing it on a
+/// touch screen, as in many tablet CAD viewers. When a finger is held still on the camera's
+/// viewport for `duration` seconds, the point under it becomes the new target focus, so the
+/// camera smoothly moves to look at it, and dragging the same finger afterwards orbits around
+/// it. The point is found by casting a ray against the bounding boxes of entities with an
+/// `Aabb`, and nothing happens if the ray doesn't hit anything.
+/// Only single finger touches that start inside the camera's viewport are considered, so
+/// multi-finger gestures are left alone.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitLongPress {
+    /// How long the finger has to be held still, in seconds. Defaults to `0.5`.
+    pub duration: f32,
+    /// How far the finger can move while being held, in logical pixels, before the touch is no
+    /// longer considered a long press. Defaults to `10.0`.
+    pub tolerance: f32,
+    /// The state of the current touch
+    #[reflect(ignore)]
+    state: LongPressState,
+}
+
+impl Default for PanOrbitLongPress {
+    fn default() -> Self {
+        PanOrbitLongPress {
+            duration: 0.5,
+            tolerance: 10.0,
+            state: LongPressState::Idle,
+        }
+    }
+}
+
+impl PanOrbitLongPress {
+    /// Whether the camera is currently being orbited by dragging after a long press.
+    pub fn is_orbiting(&self) -> bool {
+        matches!(self.state, LongPressState::Orbiting { .. })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum LongPressState {
+    /// No finger is down, or the current touch isn't a long press
+    #[default]
+    Idle,
+    /// A finger is down, and may become a long press if it's held still for long enough
+    Holding { touch: u64, start: Vec2, held: f32 },
+    /// The long press happened, and dragging the finger orbits the camera
+    Orbiting { touch: u64, last: Vec2 },
+}
+
+/// Detects long presses on cameras' viewports, and orbits the cameras while the finger is dragged
+/// afterwards
+pub(crate) fn long_press_orbit(
+    time: Res<Time<Real>>,
+    touches: Res<Touches>,
+    aabbs: Query<(&Aabb, &GlobalTransform)>,
+    mut cameras: Query<(
+        &Camera,
+        &mut PanOrbitCamera,
+        &Transform,
+        &mut PanOrbitLongPress,
+    )>,
+) {
+    for (camera, mut pan_orbit, transform, mut long_press) in cameras.iter_mut() {
+        // Touch positions are relative to the window
+        if !matches!(camera.target, RenderTarget::Window(_)) {
+            continue;
+        }
+        let viewport_min = camera
+            .logical_viewport_rect()
+            .map_or(Vec2::ZERO, |rect| rect.min);
+        let Some(viewport_size) = camera.logical_viewport_size() else {
+            continue;
+        };
+        let contains = |position: Vec2| {
+            let position = position - viewport_min;
+            position.cmpge(Vec2::ZERO).all() && position.cmple(viewport_size).all()
+        };
+
+        long_press.state = match long_press.state {
+            LongPressState::Idle => match touches.iter_just_pressed().next() {
+                Some(touch) if touches.iter().count() == 1 && contains(touch.position()) => {
+                    LongPressState::Holding {
+                        touch: touch.id(),
+                        start: touch.position(),
+                        held: 0.0,
+                    }
+                }
+                _ => LongPressState::Idle,
+            },
+            LongPressState::Holding { touch, start, held } => {
+                let held = held + time.delta_seconds();
+                match touches.get_pressed(touch) {
+                    Some(pressed)
+                        if touches.iter().count() == 1
+                            && pressed.position().distance(start) <= long_press.tolerance =>
+                    {
+                        if held < long_press.duration {
+                            LongPressState::Holding { touch, start, held }
+                        } else {
+                            let hit = camera
+                                .viewport_to_world(
+                                    &GlobalTransform::from(*transform),
+                                    pressed.position() - viewport_min,
+                                )
+                                .and_then(|ray| {
+                                    util::raycast_aabbs(ray.origin, ray.direction, aabbs.iter())
+                                });
+                            match hit {
+                                Some(hit) => {
+                                    pan_orbit.target_focus = hit;
+                                    LongPressState::Orbiting {
+                                        touch,
+                                        last: pressed.position(),
+                                    }
+                                }
+                                None => LongPressState::Idle,
+                            }
+                        }
+                    }
+                    _ => LongPressState::Idle,
+                }
+            }
+            LongPressState::Orbiting { touch, last } => match touches.get_pressed(touch) {
+                Some(pressed) => {
+                    let delta = (pressed.position() - last) * pan_orbit.orbit.sensitivity;
+                    if delta != Vec2::ZERO {
+                        // Dragging across the full viewport orbits a full turn horizontally, and
+                        // a half turn vertically, like dragging with the mouse
+                        let mut delta_x = delta.x / viewport_size.x * TAU;
+                        let mut delta_y = delta.y / viewport_size.y * PI;
+                        if pan_orbit.is_upside_down != pan_orbit.orbit.invert_x {
+                            delta_x = -delta_x;
+                        }
+                        if pan_orbit.orbit.invert_y {
+                            delta_y = -delta_y;
+                        }
+                        if !pan_orbit.orbit.lock_alpha {
+                            pan_orbit.target_alpha -= delta_x;
+                        }
+                        if !pan_orbit.orbit.lock_beta {
+                            pan_orbit.target_beta += delta_y;
+                        }
+                    }
+                    LongPressState::Orbiting {
+                        touch,
+                        last: pressed.position(),
+                    }
+                }
+                None => LongPressState::Idle,
+            },
+        };
+    }
+}
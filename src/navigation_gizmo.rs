@@ -0,0 +1,155 @@
+use crate::PanOrbitCamera;
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+/// Add this to an entity with `PanOrbitCamera` to show a clickable orientation widget in the
+/// top right corner of the camera's viewport. The widget shows the direction of each world axis
+/// (X red, Y green, Z blue, with the negative axes faded), and clicking an axis smoothly orbits
+/// the camera to look along it, e.g. clicking +Y gives a top-down view.
+/// The widget is made of UI nodes that are spawned and despawned automatically. Only cameras
+/// that render to the primary window are supported.
+/// Requires the `navigation_gizmo` feature.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitNavigationGizmo {
+    /// The width and height of the widget, in logical pixels. Defaults to `100.0`.
+    pub size: f32,
+    /// The distance from the edges of the viewport, in logical pixels. Defaults to `10.0`.
+    pub margin: f32,
+}
+
+impl Default for PanOrbitNavigationGizmo {
+    fn default() -> Self {
+        PanOrbitNavigationGizmo {
+            size: 100.0,
+            margin: 10.0,
+        }
+    }
+}
+
+/// The root UI node of a navigation gizmo
+#[derive(Component)]
+pub(crate) struct NavigationGizmoRoot {
+    camera: Entity,
+}
+
+/// One of the clickable axis handles of a navigation gizmo
+#[derive(Component)]
+pub(crate) struct NavigationGizmoHandle {
+    camera: Entity,
+    axis: Vec3,
+}
+
+const AXES: [(Vec3, Color); 6] = [
+    (Vec3::X, Color::rgb(0.9, 0.2, 0.2)),
+    (Vec3::Y, Color::rgb(0.2, 0.8, 0.2)),
+    (Vec3::Z, Color::rgb(0.2, 0.4, 0.9)),
+    (Vec3::NEG_X, Color::rgba(0.9, 0.2, 0.2, 0.4)),
+    (Vec3::NEG_Y, Color::rgba(0.2, 0.8, 0.2, 0.4)),
+    (Vec3::NEG_Z, Color::rgba(0.2, 0.4, 0.9, 0.4)),
+];
+
+pub(crate) fn spawn_navigation_gizmos(
+    mut commands: Commands,
+    gizmos: Query<Entity, Added<PanOrbitNavigationGizmo>>,
+) {
+    for camera in gizmos.iter() {
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    ..default()
+                },
+                NavigationGizmoRoot { camera },
+            ))
+            .with_children(|parent| {
+                for (axis, color) in AXES {
+                    parent.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                ..default()
+                            },
+                            background_color: color.into(),
+                            ..default()
+                        },
+                        NavigationGizmoHandle { camera, axis },
+                    ));
+                }
+            });
+    }
+}
+
+pub(crate) fn despawn_navigation_gizmos(
+    mut commands: Commands,
+    mut removed: RemovedComponents<PanOrbitNavigationGizmo>,
+    roots: Query<(Entity, &NavigationGizmoRoot)>,
+) {
+    for camera in removed.read() {
+        for (root_entity, root) in roots.iter() {
+            if root.camera == camera {
+                commands.entity(root_entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+pub(crate) fn update_navigation_gizmos(
+    cameras: Query<(&Camera, &Transform, &PanOrbitNavigationGizmo)>,
+    mut roots: Query<(&NavigationGizmoRoot, &mut Style), Without<NavigationGizmoHandle>>,
+    mut handles: Query<(&NavigationGizmoHandle, &mut Style, &mut ZIndex)>,
+) {
+    for (root, mut style) in roots.iter_mut() {
+        let Ok((camera, _, gizmo)) = cameras.get(root.camera) else {
+            continue;
+        };
+        let Some(viewport) = camera.logical_viewport_rect() else {
+            continue;
+        };
+        style.left = Val::Px(viewport.max.x - gizmo.size - gizmo.margin);
+        style.top = Val::Px(viewport.min.y + gizmo.margin);
+        style.width = Val::Px(gizmo.size);
+        style.height = Val::Px(gizmo.size);
+    }
+
+    for (handle, mut style, mut z_index) in handles.iter_mut() {
+        let Ok((_, transform, gizmo)) = cameras.get(handle.camera) else {
+            continue;
+        };
+        // Direction of the axis as seen by the camera, where Z points towards the viewer
+        let view_dir = transform.rotation.inverse() * handle.axis;
+        let handle_size = gizmo.size * 0.2;
+        let center = gizmo.size * 0.5;
+        let reach = (gizmo.size - handle_size) * 0.5;
+        style.left = Val::Px(center + view_dir.x * reach - handle_size * 0.5);
+        style.top = Val::Px(center - view_dir.y * reach - handle_size * 0.5);
+        style.width = Val::Px(handle_size);
+        style.height = Val::Px(handle_size);
+        // Draw handles that point towards the viewer on top
+        *z_index = ZIndex::Local((view_dir.z * 100.0) as i32);
+    }
+}
+
+pub(crate) fn navigation_gizmo_clicks(
+    handles: Query<(&Interaction, &NavigationGizmoHandle), Changed<Interaction>>,
+    mut cameras: Query<&mut PanOrbitCamera>,
+) {
+    for (interaction, handle) in handles.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut pan_orbit) = cameras.get_mut(handle.camera) else {
+            continue;
+        };
+        // Move the camera to the side of the focus that the axis points to, looking back at it
+        if handle.axis.y != 0.0 {
+            pan_orbit.target_beta = handle.axis.y * PI / 2.0;
+        } else {
+            pan_orbit.set_target_alpha_wrapped(handle.axis.x.atan2(handle.axis.z));
+            pan_orbit.target_beta = 0.0;
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{CameraRecording, PanOrbitAnimation, PanOrbitCamera, PanOrbitFollow, PanOrbitRecorder};
+use bevy::prelude::*;
+
+/// Event to send when the world has been recentered by a floating origin system, which moves
+/// everything so the area around the camera stays close to the origin, to keep precision in
+/// large scenes. Every `PanOrbitCamera` is moved by the same offset, including its focus, target
+/// focus, focus limits, and transform, so the view doesn't jump and any smoothing continues
+/// where it left off. The world space positions stored by `PanOrbitFollow`, `PanOrbitAnimation`,
+/// `PanOrbitRecorder`, and the `CameraRecording` assets are moved as well. Send it from a system
+/// that runs before `OriginRebaseSystemSet`, in the same frame that the world is moved.
+/// Cameras that are children of other entities are left alone, since they move with their
+/// parent.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{OriginRebaseSystemSet, OriginRebased, PanOrbitCamera};
+/// # fn build(app: &mut App) {
+/// app.add_systems(Update, recenter.before(OriginRebaseSystemSet));
+/// # }
+/// fn recenter(
+///     mut entities: Query<&mut Transform, (Without<Parent>, Without<PanOrbitCamera>)>,
+///     mut rebased: EventWriter<OriginRebased>,
+/// ) {
+///     let offset = Vec3::new(-10000.0, 0.0, 0.0);
+///     for mut transform in entities.iter_mut() {
+///         transform.translation += offset;
+///     }
+///     rebased.send(OriginRebased { offset });
+/// }
+/// ```
+#[derive(Event, Reflect, Copy, Clone, Debug, PartialEq)]
+pub struct OriginRebased {
+    /// How far the world was moved, i.e. the new position of what used to be at the origin.
+    /// Cameras should not be moved by the sender, since they are moved by this amount when the
+    /// event is handled.
+    pub offset: Vec3,
+}
+
+impl PanOrbitCamera {
+    /// Move the focus, target focus, and focus limits by `offset`
+    fn shift_origin(&mut self, offset: Vec3) {
+        self.focus += offset;
+        self.target_focus += offset;
+        let shift = |limit: &mut Option<f32>, offset: f32| {
+            if let Some(limit) = limit {
+                *limit += offset;
+            }
+        };
+        shift(&mut self.limits.focus_upper[0], offset.x);
+        shift(&mut self.limits.focus_lower[0], offset.x);
+        shift(&mut self.limits.focus_upper[1], offset.y);
+        shift(&mut self.limits.focus_lower[1], offset.y);
+        shift(&mut self.limits.focus_upper[2], offset.z);
+        shift(&mut self.limits.focus_lower[2], offset.z);
+    }
+}
+
+/// Moves cameras, and the positions stored for them, along with the world when the origin is
+/// rebased
+pub(crate) fn rebase_origin(
+    mut rebased_events: EventReader<OriginRebased>,
+    mut orbit_cameras: Query<(&mut PanOrbitCamera, &mut Transform), Without<Parent>>,
+    mut followers: Query<&mut PanOrbitFollow>,
+    mut animations: Query<&mut PanOrbitAnimation>,
+    mut recorders: Query<&mut PanOrbitRecorder>,
+    recordings: Option<ResMut<Assets<CameraRecording>>>,
+) {
+    let offset: Vec3 = rebased_events.read().map(|event| event.offset).sum();
+    if offset == Vec3::ZERO {
+        return;
+    }
+    for (mut pan_orbit, mut transform) in orbit_cameras.iter_mut() {
+        pan_orbit.shift_origin(offset);
+        transform.translation += offset;
+    }
+    // The followed entity is moved along with the world, so its velocity stays the same
+    for mut follow in followers.iter_mut() {
+        if let Some(last_position) = follow.last_position.as_mut() {
+            *last_position += offset;
+        }
+    }
+    for mut animation in animations.iter_mut() {
+        for keyframe in animation.keyframes.iter_mut() {
+            keyframe.focus += offset;
+        }
+    }
+    for mut recorder in recorders.iter_mut() {
+        recorder.recording.shift_origin(offset);
+    }
+    if let Some(mut recordings) = recordings {
+        for (_, recording) in recordings.iter_mut() {
+            recording.shift_origin(offset);
+        }
+    }
+}
+
+impl CameraRecording {
+    /// Move the focus of every frame by `offset`
+    fn shift_origin(&mut self, offset: Vec3) {
+        for frame in self.frames.iter_mut() {
+            frame.focus += offset;
+        }
+    }
+}
+
+#[cfg(test)]
+mod shift_origin_tests {
+    use super::*;
+    use crate::{Limits, RecordedFrame};
+
+    #[test]
+    fn moves_focus_and_limits() {
+        let mut camera = PanOrbitCamera {
+            focus: Vec3::X,
+            target_focus: Vec3::Y,
+            limits: Limits {
+                focus_upper: [Some(5.0), None, None],
+                ..default()
+            },
+            ..default()
+        };
+        camera.shift_origin(Vec3::new(-10.0, 1.0, 0.0));
+        assert_eq!(camera.focus, Vec3::new(-9.0, 1.0, 0.0));
+        assert_eq!(camera.target_focus, Vec3::new(-10.0, 2.0, 0.0));
+        assert_eq!(camera.limits.focus_upper[0], Some(-5.0));
+        assert_eq!(camera.limits.focus_lower[0], None);
+    }
+
+    #[test]
+    fn moves_recorded_frames() {
+        let frame = |time: f32, focus: Vec3| RecordedFrame {
+            time,
+            focus,
+            alpha: 0.0,
+            beta: 0.0,
+            radius: 1.0,
+            scale: None,
+        };
+        let mut recording = CameraRecording {
+            frames: vec![frame(0.0, Vec3::ZERO), frame(1.0, Vec3::X)],
+        };
+        recording.shift_origin(Vec3::Y);
+        assert_eq!(recording.frames[0].focus, Vec3::Y);
+        assert_eq!(recording.frames[1].focus, Vec3::new(1.0, 1.0, 0.0));
+    }
+}
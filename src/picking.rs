@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_mod_picking::focus::HoverMap;
+use bevy_mod_picking::pointer::PointerId;
+
+/// Configures how `PanOrbitCamera` interacts with `bevy_mod_picking`. By default, mouse drags
+/// that start over a pickable entity (e.g. a gizmo or a draggable object) are left to picking,
+/// and don't move the camera. Entities that don't block picking (see `Pickable`) are ignored.
+/// Requires the `bevy_mod_picking` feature.
+#[derive(Resource, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct PanOrbitPickingSettings {
+    /// Mouse buttons that always control the camera, even when the drag starts over a pickable
+    /// entity, e.g. `vec![MouseButton::Right]` to keep panning available everywhere.
+    /// Defaults to an empty list.
+    pub claimed_buttons: Vec<MouseButton>,
+}
+
+/// Whether the current mouse drag belongs to picking rather than the camera
+#[derive(Resource, Default)]
+pub(crate) struct PickingDragState {
+    pub(crate) blocked: bool,
+}
+
+pub(crate) fn update_picking_drag_state(
+    settings: Res<PanOrbitPickingSettings>,
+    mouse_input: Res<Input<MouseButton>>,
+    hover_map: Option<Res<HoverMap>>,
+    mut state: ResMut<PickingDragState>,
+) {
+    let over_pickable = hover_map.is_some_and(|hover_map| {
+        hover_map
+            .get(&PointerId::Mouse)
+            .is_some_and(|hovered| !hovered.is_empty())
+    });
+    if over_pickable
+        && mouse_input
+            .get_just_pressed()
+            .any(|button| !settings.claimed_buttons.contains(button))
+    {
+        state.blocked = true;
+    } else if mouse_input.get_pressed().next().is_none() {
+        state.blocked = false;
+    }
+}
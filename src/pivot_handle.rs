@@ -0,0 +1,215 @@
+use crate::{util, ActiveCameraData, PanOrbitCamera};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Add this to an entity with `PanOrbitCamera` to show a handle at the focus point that can be
+/// dragged to move the focus along the ground plane (the horizontal plane through the focus),
+/// as an alternative to panning. While the handle is being dragged, the camera doesn't orbit or
+/// pan.
+/// The handle is drawn with gizmos, so it is visible to all cameras that render gizmos.
+/// Requires the `pivot_handle` feature.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitPivotHandle {
+    /// The color of the handle. Defaults to semi-transparent white.
+    pub color: Color,
+    /// The color of the handle while it is being dragged. Defaults to yellow.
+    pub drag_color: Color,
+    /// The radius of the handle, as a fraction of the camera's `radius` (or `scale`, for
+    /// orthographic cameras), so the handle stays the same size on screen when zooming.
+    /// Defaults to `0.03`.
+    pub size: f32,
+    /// Mouse button used to drag the handle. Defaults to `MouseButton::Left`.
+    pub button: MouseButton,
+    /// Where the handle was grabbed, while it is being dragged
+    #[reflect(ignore)]
+    grab: Option<PivotGrab>,
+}
+
+/// Where a pivot handle was grabbed. The camera moves along with the focus while dragging, so the
+/// cursor is measured against the camera transform and ground plane from the moment of grabbing,
+/// otherwise the focus would keep sliding after the cursor stops.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct PivotGrab {
+    /// The offset from the focus to the point on the ground plane where the handle was grabbed
+    offset: Vec3,
+    /// The camera's transform when the handle was grabbed
+    camera_transform: GlobalTransform,
+    /// The focus when the handle was grabbed, which the ground plane passes through
+    plane_origin: Vec3,
+}
+
+impl Default for PanOrbitPivotHandle {
+    fn default() -> Self {
+        PanOrbitPivotHandle {
+            color: Color::rgba(1.0, 1.0, 1.0, 0.6),
+            drag_color: Color::YELLOW,
+            size: 0.03,
+            button: MouseButton::Left,
+            grab: None,
+        }
+    }
+}
+
+impl PanOrbitPivotHandle {
+    /// Whether the handle is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.grab.is_some()
+    }
+}
+
+/// Whether a pivot handle is being dragged, in which case mouse motion doesn't move the camera
+#[derive(Resource, Default)]
+pub(crate) struct PivotHandleDragState {
+    pub(crate) dragging: bool,
+}
+
+/// The radius of the handle in world units, or `None` if the camera isn't initialized yet
+fn handle_radius(
+    pan_orbit: &PanOrbitCamera,
+    projection: &Projection,
+    handle: &PanOrbitPivotHandle,
+) -> Option<f32> {
+    let zoom = match projection {
+        Projection::Perspective(_) => pan_orbit.radius,
+        Projection::Orthographic(_) => pan_orbit.scale,
+    };
+    zoom.map(|zoom| zoom * handle.size)
+}
+
+/// Returns the distance along the ray at which it enters the sphere, or `None` if it misses. If
+/// the ray starts inside the sphere, the distance is `0.0`
+fn ray_sphere_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    center: Vec3,
+    radius: f32,
+) -> Option<f32> {
+    let direction = direction.normalize_or_zero();
+    let to_center = center - origin;
+    let closest = to_center.dot(direction);
+    let distance_squared = to_center.length_squared() - closest * closest;
+    let radius_squared = radius * radius;
+    if distance_squared > radius_squared {
+        return None;
+    }
+    let half_chord = (radius_squared - distance_squared).sqrt();
+    let t_exit = closest + half_chord;
+    (t_exit >= 0.0).then(|| (closest - half_chord).max(0.0))
+}
+
+/// Starts, updates, and ends drags of the active camera's pivot handle
+pub(crate) fn drag_pivot_handles(
+    active_cam: Res<ActiveCameraData>,
+    mouse_input: Res<Input<MouseButton>>,
+    mut drag_state: ResMut<PivotHandleDragState>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    mut cameras: Query<(
+        Entity,
+        &Camera,
+        &mut PanOrbitCamera,
+        &Transform,
+        &Projection,
+        &mut PanOrbitPivotHandle,
+    )>,
+) {
+    let mut dragging = false;
+    for (entity, camera, mut pan_orbit, transform, projection, mut handle) in cameras.iter_mut() {
+        if !mouse_input.pressed(handle.button) || active_cam.entity != Some(entity) {
+            if handle.grab.is_some() {
+                handle.grab = None;
+            }
+            continue;
+        }
+        let Some(cursor_pos) =
+            util::cursor_viewport_position(camera, &primary_windows, &other_windows)
+        else {
+            continue;
+        };
+
+        if mouse_input.just_pressed(handle.button) {
+            let camera_transform = GlobalTransform::from(*transform);
+            let (Some(ray), Some(radius)) = (
+                camera.viewport_to_world(&camera_transform, cursor_pos),
+                handle_radius(&pan_orbit, projection, &handle),
+            ) else {
+                continue;
+            };
+            if ray_sphere_intersection(ray.origin, ray.direction, pan_orbit.focus, radius).is_some()
+            {
+                let ground_hit = util::ray_plane_intersection(
+                    ray.origin,
+                    ray.direction,
+                    pan_orbit.focus,
+                    Vec3::Y,
+                );
+                handle.grab = Some(PivotGrab {
+                    offset: ground_hit.map_or(Vec3::ZERO, |hit| hit - pan_orbit.focus),
+                    camera_transform,
+                    plane_origin: pan_orbit.focus,
+                });
+            }
+        } else if let Some(grab) = handle.grab {
+            let ground_hit = camera
+                .viewport_to_world(&grab.camera_transform, cursor_pos)
+                .and_then(|ray| {
+                    util::ray_plane_intersection(
+                        ray.origin,
+                        ray.direction,
+                        grab.plane_origin,
+                        Vec3::Y,
+                    )
+                });
+            if let Some(hit) = ground_hit {
+                let target = hit - grab.offset;
+                if pan_orbit.target_focus != target {
+                    pan_orbit.target_focus = target;
+                }
+            }
+        }
+        dragging |= handle.grab.is_some();
+    }
+    drag_state.dragging = dragging;
+}
+
+/// Draws the pivot handles at their camera's focus point
+pub(crate) fn draw_pivot_handles(
+    mut gizmos: Gizmos,
+    cameras: Query<(&PanOrbitCamera, &Projection, &PanOrbitPivotHandle)>,
+) {
+    for (pan_orbit, projection, handle) in cameras.iter() {
+        let Some(radius) = handle_radius(pan_orbit, projection, handle) else {
+            continue;
+        };
+        let color = match handle.is_dragging() {
+            true => handle.drag_color,
+            false => handle.color,
+        };
+        gizmos.sphere(pan_orbit.focus, Quat::IDENTITY, radius, color);
+        gizmos.circle(pan_orbit.focus, Vec3::Y, radius * 2.0, color);
+    }
+}
+
+#[cfg(test)]
+mod ray_sphere_intersection_tests {
+    use super::*;
+
+    #[test]
+    fn hits_front_of_sphere() {
+        let hit = ray_sphere_intersection(Vec3::Z * 5.0, Vec3::NEG_Z, Vec3::ZERO, 1.0);
+        assert_eq!(hit, Some(4.0));
+    }
+
+    #[test]
+    fn misses_sphere_beside_or_behind_ray() {
+        assert_eq!(
+            ray_sphere_intersection(Vec3::new(2.0, 0.0, 5.0), Vec3::NEG_Z, Vec3::ZERO, 1.0),
+            None
+        );
+        assert_eq!(
+            ray_sphere_intersection(Vec3::Z * 5.0, Vec3::Z, Vec3::ZERO, 1.0),
+            None
+        );
+    }
+}
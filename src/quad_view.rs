@@ -0,0 +1,210 @@
+use crate::{ActiveCameraData, OrbitSettings, PanOrbitCamera, PanOrbitCameraSubSet};
+use bevy::core_pipeline::clear_color::ClearColorConfig;
+use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::window::PrimaryWindow;
+use std::f32::consts::PI;
+
+/// Plugin that spawns a four-way view, as found in CAD and modeling tools: a perspective camera
+/// in the top left quarter of the primary window, and orthographic top, front, and right cameras
+/// in the other quarters. Each view is a regular `PanOrbitCamera` that responds to input over
+/// its own quarter. The orthographic views can only pan and zoom, and all four views share the
+/// same focus, so panning in one view pans all of them.
+/// Configure the views with `QuadViewSettings`, and find the cameras with the `QuadViewport`
+/// component. Requires `PanOrbitCameraPlugin` and the `quad_view` feature.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_panorbit_camera::{PanOrbitCameraPlugin, QuadViewPlugin};
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins((PanOrbitCameraPlugin, QuadViewPlugin))
+///         .run();
+/// }
+/// ```
+pub struct QuadViewPlugin;
+
+impl Plugin for QuadViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<QuadViewSettings>()
+            .register_type::<QuadViewSettings>()
+            .register_type::<QuadViewport>()
+            .add_systems(Startup, spawn_quad_view)
+            .add_systems(
+                Update,
+                (
+                    layout_quad_view.before(PanOrbitCameraSubSet::InputGather),
+                    sync_quad_view_focus
+                        .in_set(PanOrbitCameraSubSet::ProcessMotion)
+                        .after(crate::pan_orbit_camera),
+                ),
+            );
+    }
+}
+
+/// Settings for the cameras spawned by `QuadViewPlugin`. Changes after startup have no effect.
+#[derive(Resource, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct QuadViewSettings {
+    /// The initial focus of all four views. Defaults to `Vec3::ZERO`.
+    pub focus: Vec3,
+    /// The initial distance of the cameras from the focus. For the orthographic views, this is
+    /// also the initial height of the visible area. Defaults to `10.0`.
+    pub radius: f32,
+    /// The `PanOrbitCamera` settings that the views start with. The focus, alpha, beta, radius,
+    /// and scale are overwritten, and the orthographic views have orbiting locked.
+    /// Defaults to `PanOrbitCamera::default()`.
+    pub camera: PanOrbitCamera,
+}
+
+impl Default for QuadViewSettings {
+    fn default() -> Self {
+        QuadViewSettings {
+            focus: Vec3::ZERO,
+            radius: 10.0,
+            camera: PanOrbitCamera::default(),
+        }
+    }
+}
+
+/// Identifies the cameras spawned by `QuadViewPlugin`.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(Component, Default)]
+pub enum QuadViewport {
+    /// The perspective view, in the top left quarter. This is the default.
+    #[default]
+    Perspective,
+    /// The orthographic view looking down the Y axis, in the top right quarter.
+    Top,
+    /// The orthographic view looking down the Z axis, in the bottom left quarter.
+    Front,
+    /// The orthographic view looking down the X axis, in the bottom right quarter.
+    Right,
+}
+
+impl QuadViewport {
+    const ALL: [QuadViewport; 4] = [
+        QuadViewport::Perspective,
+        QuadViewport::Top,
+        QuadViewport::Front,
+        QuadViewport::Right,
+    ];
+
+    /// The quarter of the window, as a column and row
+    fn cell(self) -> UVec2 {
+        match self {
+            QuadViewport::Perspective => UVec2::new(0, 0),
+            QuadViewport::Top => UVec2::new(1, 0),
+            QuadViewport::Front => UVec2::new(0, 1),
+            QuadViewport::Right => UVec2::new(1, 1),
+        }
+    }
+
+    /// The alpha and beta the view looks from
+    fn angles(self) -> (f32, f32) {
+        match self {
+            QuadViewport::Perspective => (PI / 4.0, PI / 6.0),
+            QuadViewport::Top => (0.0, PI / 2.0),
+            QuadViewport::Front => (0.0, 0.0),
+            QuadViewport::Right => (PI / 2.0, 0.0),
+        }
+    }
+}
+
+fn spawn_quad_view(mut commands: Commands, settings: Res<QuadViewSettings>) {
+    for (order, viewport) in QuadViewport::ALL.into_iter().enumerate() {
+        let (alpha, beta) = viewport.angles();
+        let orthographic = viewport != QuadViewport::Perspective;
+        let projection = match orthographic {
+            true => Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical(1.0),
+                ..default()
+            }),
+            false => Projection::Perspective(default()),
+        };
+        commands.spawn((
+            Camera3dBundle {
+                camera: Camera {
+                    order: order as isize,
+                    ..default()
+                },
+                camera_3d: Camera3d {
+                    // The views don't overlap, so only the first one needs to clear the window
+                    clear_color: match order {
+                        0 => ClearColorConfig::Default,
+                        _ => ClearColorConfig::None,
+                    },
+                    ..default()
+                },
+                projection,
+                ..default()
+            },
+            PanOrbitCamera {
+                focus: settings.focus,
+                alpha: Some(alpha),
+                beta: Some(beta),
+                radius: Some(settings.radius),
+                scale: orthographic.then_some(settings.radius),
+                orbit: OrbitSettings {
+                    lock_alpha: orthographic || settings.camera.orbit.lock_alpha,
+                    lock_beta: orthographic || settings.camera.orbit.lock_beta,
+                    ..settings.camera.orbit
+                },
+                initialized: false,
+                ..settings.camera
+            },
+            viewport,
+        ));
+    }
+}
+
+/// Fits each view to its quarter of the window
+fn layout_quad_view(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Camera, &QuadViewport)>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let size = UVec2::new(
+        window.resolution.physical_width(),
+        window.resolution.physical_height(),
+    ) / 2;
+    if size.cmpeq(UVec2::ZERO).any() {
+        return;
+    }
+    for (mut camera, viewport) in cameras.iter_mut() {
+        let position = viewport.cell() * size;
+        // Avoid triggering change detection if nothing changed
+        let unchanged = camera.viewport.as_ref().is_some_and(|current| {
+            current.physical_position == position && current.physical_size == size
+        });
+        if !unchanged {
+            camera.viewport = Some(Viewport {
+                physical_position: position,
+                physical_size: size,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Copies the focus of the active view to the other views
+fn sync_quad_view_focus(
+    active_cam: Res<ActiveCameraData>,
+    mut cameras: Query<&mut PanOrbitCamera, With<QuadViewport>>,
+) {
+    let Some(focus) = active_cam
+        .entity
+        .and_then(|entity| cameras.get(entity).ok())
+        .map(|pan_orbit| pan_orbit.target_focus)
+    else {
+        return;
+    };
+    for mut pan_orbit in cameras.iter_mut() {
+        if pan_orbit.target_focus != focus {
+            pan_orbit.target_focus = focus;
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{Clocks, PanOrbitCamera, PanOrbitCameraSubSet};
+use bevy::prelude::*;
+
+/// Adds support for recording camera movement with `PanOrbitRecorder`, and playing it back with
+/// `PanOrbitPlayback`. Useful for creating flythroughs, or reproducing bug reports about camera
+/// behavior. Requires `PanOrbitCameraPlugin` and Bevy's `AssetPlugin`.
+pub struct PanOrbitRecordingPlugin;
+
+impl Plugin for PanOrbitRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CameraRecording>()
+            .register_asset_reflect::<CameraRecording>()
+            .register_type::<PanOrbitRecorder>()
+            .register_type::<PanOrbitPlayback>()
+            .add_systems(
+                Update,
+                (
+                    play_recordings
+                        .in_set(PanOrbitCameraSubSet::ProcessMotion)
+                        .before(crate::pan_orbit_camera),
+                    record_cameras
+                        .in_set(PanOrbitCameraSubSet::ApplyTransform)
+                        .after(crate::apply_pan_orbit_transforms),
+                ),
+            );
+    }
+}
+
+/// The state of a `PanOrbitCamera` at a single point in time.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+pub struct RecordedFrame {
+    /// Time in seconds since the start of the recording.
+    pub time: f32,
+    /// The focus point.
+    pub focus: Vec3,
+    /// The alpha value, in radians.
+    pub alpha: f32,
+    /// The beta value, in radians.
+    pub beta: f32,
+    /// The radius.
+    pub radius: f32,
+    /// The orthographic scale, if any.
+    pub scale: Option<f32>,
+}
+
+/// A recording of a `PanOrbitCamera`'s movement, as a sequence of frames sorted by time.
+/// Created by `PanOrbitRecorder::finish`.
+#[derive(Asset, Reflect, Clone, Debug, Default, PartialEq)]
+pub struct CameraRecording {
+    /// The recorded frames, sorted by `time`.
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl CameraRecording {
+    /// The length of the recording in seconds.
+    pub fn duration(&self) -> f32 {
+        self.frames.last().map_or(0.0, |frame| frame.time)
+    }
+
+    /// The camera state at the given time, interpolated between the nearest frames. Returns
+    /// `None` if the recording is empty.
+    pub fn sample(&self, time: f32) -> Option<RecordedFrame> {
+        let next_index = self.frames.iter().position(|f| f.time >= time);
+        match next_index {
+            None => self.frames.last().copied(),
+            Some(0) => self.frames.first().copied(),
+            Some(i) => {
+                let from = self.frames[i - 1];
+                let to = self.frames[i];
+                let t = (time - from.time) / (to.time - from.time);
+                let lerp = |a: f32, b: f32| a + (b - a) * t;
+                Some(RecordedFrame {
+                    time,
+                    focus: from.focus.lerp(to.focus, t),
+                    alpha: lerp(from.alpha, to.alpha),
+                    beta: lerp(from.beta, to.beta),
+                    radius: lerp(from.radius, to.radius),
+                    scale: from.scale.zip(to.scale).map(|(a, b)| lerp(a, b)),
+                })
+            }
+        }
+    }
+}
+
+/// Add this to an entity with `PanOrbitCamera` to record its movement every frame. Call
+/// `finish` to get the recording, and add it to `Assets<CameraRecording>` to play it back.
+/// Requires `PanOrbitRecordingPlugin`.
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitRecorder {
+    /// The frames recorded so far.
+    pub recording: CameraRecording,
+    /// How long the recorder has been recording, in seconds. Updated automatically.
+    pub elapsed: f32,
+    /// Whether the recorder is currently recording. Defaults to `true` when created with `new`.
+    pub recording_active: bool,
+}
+
+impl PanOrbitRecorder {
+    /// Create a recorder that starts recording immediately.
+    pub fn new() -> Self {
+        PanOrbitRecorder {
+            recording_active: true,
+            ..default()
+        }
+    }
+
+    /// Stop recording, and return the recording. The recorder is reset, so it can be used to
+    /// record again after setting `recording_active` to `true`.
+    pub fn finish(&mut self) -> CameraRecording {
+        self.recording_active = false;
+        self.elapsed = 0.0;
+        std::mem::take(&mut self.recording)
+    }
+}
+
+/// Add this to an entity with `PanOrbitCamera` to play back a `CameraRecording`. The camera
+/// exactly follows the recording regardless of its smoothing settings, so playback is
+/// deterministic. You probably want to disable the controls during playback using
+/// `PanOrbitCamera::enabled`.
+/// Requires `PanOrbitRecordingPlugin`.
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitPlayback {
+    /// The recording to play.
+    pub recording: Handle<CameraRecording>,
+    /// How far into the recording playback is, in seconds. Updated automatically.
+    pub elapsed: f32,
+    /// Whether to start again from the beginning after reaching the end. Defaults to `false`.
+    pub looping: bool,
+    /// Whether the recording is currently playing. Automatically set to `false` once a
+    /// non-looping playback reaches the end.
+    pub playing: bool,
+}
+
+impl PanOrbitPlayback {
+    /// Create a playback of the given recording, which starts playing immediately.
+    pub fn new(recording: Handle<CameraRecording>) -> Self {
+        PanOrbitPlayback {
+            recording,
+            elapsed: 0.0,
+            looping: false,
+            playing: true,
+        }
+    }
+
+    /// Make the playback loop.
+    pub fn looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+}
+
+/// Appends the current state of each recording camera to its recorder
+fn record_cameras(clocks: Clocks, mut recorders: Query<(&mut PanOrbitRecorder, &PanOrbitCamera)>) {
+    for (mut recorder, pan_orbit) in recorders.iter_mut() {
+        if !recorder.recording_active {
+            continue;
+        }
+        let (Some(alpha), Some(beta), Some(radius)) =
+            (pan_orbit.alpha, pan_orbit.beta, pan_orbit.radius)
+        else {
+            continue;
+        };
+        let time = recorder.elapsed;
+        recorder.recording.frames.push(RecordedFrame {
+            time,
+            focus: pan_orbit.focus,
+            alpha,
+            beta,
+            radius,
+            scale: pan_orbit.scale,
+        });
+        recorder.elapsed += clocks.delta_seconds(pan_orbit.time_source);
+    }
+}
+
+/// Advances each playback and snaps its camera to the recorded state
+fn play_recordings(
+    clocks: Clocks,
+    recordings: Res<Assets<CameraRecording>>,
+    mut playbacks: Query<(&mut PanOrbitPlayback, &mut PanOrbitCamera)>,
+) {
+    for (mut playback, mut pan_orbit) in playbacks.iter_mut() {
+        if !playback.playing {
+            continue;
+        }
+        let Some(recording) = recordings.get(&playback.recording) else {
+            continue;
+        };
+
+        let duration = recording.duration();
+        playback.elapsed += clocks.animation_delta_seconds(&pan_orbit);
+        if playback.elapsed >= duration {
+            if playback.looping && duration > 0.0 {
+                playback.elapsed %= duration;
+            } else {
+                playback.elapsed = duration;
+                playback.playing = false;
+            }
+        }
+
+        if let Some(frame) = recording.sample(playback.elapsed) {
+            // Set both the current and target values, so smoothing has no effect
+            pan_orbit.focus = frame.focus;
+            pan_orbit.target_focus = frame.focus;
+            pan_orbit.alpha = Some(frame.alpha);
+            pan_orbit.target_alpha = frame.alpha;
+            pan_orbit.beta = Some(frame.beta);
+            pan_orbit.target_beta = frame.beta;
+            pan_orbit.radius = Some(frame.radius);
+            pan_orbit.target_radius = frame.radius;
+            if let Some(scale) = frame.scale {
+                pan_orbit.scale = Some(scale);
+                pan_orbit.target_scale = scale;
+            }
+            pan_orbit.force_update = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod sample_tests {
+    use super::*;
+
+    fn frame(time: f32, alpha: f32) -> RecordedFrame {
+        RecordedFrame {
+            time,
+            focus: Vec3::ZERO,
+            alpha,
+            beta: 0.0,
+            radius: 1.0,
+            scale: None,
+        }
+    }
+
+    #[test]
+    fn empty_recording_returns_none() {
+        assert_eq!(CameraRecording::default().sample(1.0), None);
+    }
+
+    #[test]
+    fn interpolates_between_frames() {
+        let recording = CameraRecording {
+            frames: vec![frame(0.0, 0.0), frame(0.5, 1.0)],
+        };
+        assert_eq!(recording.sample(0.25).unwrap().alpha, 0.5);
+        assert_eq!(recording.sample(1.0).unwrap().alpha, 1.0);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::PanOrbitCamera;
+use bevy::prelude::*;
+
+impl PanOrbitCamera {
+    /// Recalculate the focus, alpha, beta, and roll from a `Transform` that was changed by
+    /// something else, e.g. physics or a cutscene, so control can be handed back to the camera
+    /// without it snapping back to where it was. The radius is kept, so the new focus is `radius`
+    /// in front of the camera, and any smoothing or momentum is cleared.
+    /// See `auto_resync_transform` to do this automatically.
+    pub fn resync_from_transform(&mut self, transform: &Transform) {
+        let radius = self.radius.unwrap_or(self.target_radius);
+        // Undo the orientation offset, then split the rotation into alpha, beta, and roll, in the
+        // order they are applied by the plugin
+        let rotation = self.orientation_offset.inverse() * transform.rotation;
+        let (alpha, pitch, roll) = rotation.to_euler(EulerRot::YXZ);
+        let focus = transform.translation
+            - transform.rotation * (Vec3::new(0.0, 0.0, radius) + self.focus_offset);
+        self.set_immediate(alpha, -pitch, radius, focus);
+        self.roll = roll;
+        self.target_roll = roll;
+    }
+}
+
+/// Resyncs cameras with `auto_resync_transform` whose transform was changed by something other
+/// than the plugin
+pub(crate) fn resync_changed_transforms(
+    mut orbit_cameras: Query<(&mut PanOrbitCamera, &Transform), Changed<Transform>>,
+) {
+    for (mut pan_orbit, transform) in orbit_cameras.iter_mut() {
+        if !pan_orbit.auto_resync_transform || !pan_orbit.initialized {
+            continue;
+        }
+        // The plugin's own writes match the current values, apart from changes smaller than
+        // `transform_epsilon` that it skips
+        let expected = pan_orbit.transform();
+        let tolerance = pan_orbit.transform_epsilon.max(0.0001);
+        if crate::util::transform_differs(transform, &expected, tolerance) {
+            pan_orbit.resync_from_transform(transform);
+        }
+    }
+}
+
+#[cfg(test)]
+mod resync_from_transform_tests {
+    use super::*;
+
+    #[test]
+    fn matches_moved_transform() {
+        let mut camera = PanOrbitCamera::from_yaw_pitch_radius(0.3, 0.2, 5.0, Vec3::ZERO);
+        let moved = PanOrbitCamera {
+            roll: 0.1,
+            ..PanOrbitCamera::from_yaw_pitch_radius(1.2, -0.4, 5.0, Vec3::new(1.0, 2.0, 3.0))
+        }
+        .transform();
+        camera.resync_from_transform(&moved);
+
+        assert!(camera.focus.abs_diff_eq(Vec3::new(1.0, 2.0, 3.0), 1e-4));
+        assert!((camera.target_alpha - 1.2).abs() < 1e-4);
+        assert!((camera.target_beta + 0.4).abs() < 1e-4);
+        assert!((camera.roll - 0.1).abs() < 1e-4);
+        let transform = camera.transform();
+        assert!(transform.translation.abs_diff_eq(moved.translation, 1e-4));
+    }
+}
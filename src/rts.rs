@@ -0,0 +1,178 @@
+use crate::{util, ActiveCameraData, Clocks, PanOrbitCamera, PanOrbitZoomBounds};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::f32::consts::PI;
+
+/// Add this to an entity with `PanOrbitCamera` to control it like a strategy game camera. The
+/// pitch (`beta`) is set automatically based on the zoom, so the camera tilts towards a top-down
+/// view as it zooms out. Keys rotate the camera around the focus, and keys or moving the cursor
+/// to the edge of the viewport pan the focus along the ground (the XZ plane), regardless of the
+/// camera's pitch.
+/// The tilt curve goes from `Limits::zoom_lower` to `Limits::zoom_upper`, so set the upper limit,
+/// otherwise the pitch stays at `pitch_zoomed_in`. Without a lower limit, the curve starts at
+/// the same default lower limit the camera uses (see `PanOrbitZoomBounds`). The
+/// `PanOrbitCamera`'s own mouse controls still work, but any change to the pitch is overridden.
+/// Consider combining this with `PanOrbitFocusHeight` to follow terrain.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct RtsCameraController {
+    /// The pitch when fully zoomed in, in radians. Defaults to `PI / 4.0`.
+    pub pitch_zoomed_in: f32,
+    /// The pitch when fully zoomed out, in radians. Defaults to `PI * 0.45` (almost top-down).
+    pub pitch_zoomed_out: f32,
+    /// Key that rotates the camera to the left around the focus. Defaults to `Some(KeyCode::Q)`.
+    pub key_rotate_left: Option<KeyCode>,
+    /// Key that rotates the camera to the right around the focus. Defaults to
+    /// `Some(KeyCode::E)`.
+    pub key_rotate_right: Option<KeyCode>,
+    /// How fast the rotation keys rotate the camera, in radians per second. Defaults to `2.0`.
+    pub rotate_speed: f32,
+    /// Key that pans forward. Defaults to `Some(KeyCode::W)`.
+    pub key_pan_forward: Option<KeyCode>,
+    /// Key that pans backward. Defaults to `Some(KeyCode::S)`.
+    pub key_pan_back: Option<KeyCode>,
+    /// Key that pans left. Defaults to `Some(KeyCode::A)`.
+    pub key_pan_left: Option<KeyCode>,
+    /// Key that pans right. Defaults to `Some(KeyCode::D)`.
+    pub key_pan_right: Option<KeyCode>,
+    /// If set, the camera pans when the cursor is within this many logical pixels of the edge
+    /// of the viewport. Defaults to `Some(10.0)`.
+    pub edge_pan_margin: Option<f32>,
+    /// How fast keyboard and edge panning pan, as a multiple of the radius per second, so the
+    /// camera pans faster when zoomed out. Defaults to `1.0`.
+    pub pan_speed: f32,
+}
+
+impl Default for RtsCameraController {
+    fn default() -> Self {
+        RtsCameraController {
+            pitch_zoomed_in: PI / 4.0,
+            pitch_zoomed_out: PI * 0.45,
+            key_rotate_left: Some(KeyCode::Q),
+            key_rotate_right: Some(KeyCode::E),
+            rotate_speed: 2.0,
+            key_pan_forward: Some(KeyCode::W),
+            key_pan_back: Some(KeyCode::S),
+            key_pan_left: Some(KeyCode::A),
+            key_pan_right: Some(KeyCode::D),
+            edge_pan_margin: Some(10.0),
+            pan_speed: 1.0,
+        }
+    }
+}
+
+impl RtsCameraController {
+    /// The pitch for the given radius, interpolated between `pitch_zoomed_in` and
+    /// `pitch_zoomed_out` based on where the radius is between the zoom limits.
+    pub fn pitch_for_radius(&self, radius: f32, zoom_lower: f32, zoom_upper: f32) -> f32 {
+        let t = match zoom_upper > zoom_lower {
+            true => ((radius - zoom_lower) / (zoom_upper - zoom_lower)).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        self.pitch_zoomed_in + (self.pitch_zoomed_out - self.pitch_zoomed_in) * t
+    }
+}
+
+/// Applies the RTS controls and the zoom-dependent pitch to each controlled camera
+pub(crate) fn rts_camera_controller(
+    clocks: Clocks,
+    zoom_bounds: Res<PanOrbitZoomBounds>,
+    active_cam: Res<ActiveCameraData>,
+    key_input: Res<Input<KeyCode>>,
+    primary_windows: Query<&Window, With<PrimaryWindow>>,
+    other_windows: Query<&Window, Without<PrimaryWindow>>,
+    mut orbit_cameras: Query<(
+        Entity,
+        &Camera,
+        &Projection,
+        &RtsCameraController,
+        &mut PanOrbitCamera,
+    )>,
+) {
+    for (entity, camera, projection, rts, mut pan_orbit) in orbit_cameras.iter_mut() {
+        if pan_orbit.enabled && active_cam.entity == Some(entity) {
+            let dt = clocks.delta_seconds(pan_orbit.time_source);
+            let pressed = |key: Option<KeyCode>| key.is_some_and(|key| key_input.pressed(key));
+
+            let mut rotation = 0.0;
+            if pressed(rts.key_rotate_left) {
+                rotation -= 1.0;
+            }
+            if pressed(rts.key_rotate_right) {
+                rotation += 1.0;
+            }
+            if rotation != 0.0 {
+                pan_orbit.target_alpha += rotation * rts.rotate_speed * dt;
+            }
+
+            // Screen space direction, so positive Y is down, i.e. backward
+            let mut direction = Vec2::ZERO;
+            if pressed(rts.key_pan_forward) {
+                direction.y -= 1.0;
+            }
+            if pressed(rts.key_pan_back) {
+                direction.y += 1.0;
+            }
+            if pressed(rts.key_pan_left) {
+                direction.x -= 1.0;
+            }
+            if pressed(rts.key_pan_right) {
+                direction.x += 1.0;
+            }
+            if let Some(margin) = rts.edge_pan_margin {
+                let cursor_pos =
+                    util::cursor_viewport_position(camera, &primary_windows, &other_windows);
+                if let (Some(cursor_pos), Some(vp_size)) =
+                    (cursor_pos, camera.logical_viewport_size())
+                {
+                    direction += util::edge_pan_direction(cursor_pos, vp_size, margin);
+                }
+            }
+            if direction != Vec2::ZERO {
+                // Pan along the ground, relative to the direction the camera is facing
+                let alpha = pan_orbit.target_alpha;
+                let right = Vec3::new(alpha.cos(), 0.0, -alpha.sin());
+                let back = Vec3::new(alpha.sin(), 0.0, alpha.cos());
+                let radius = pan_orbit.radius.unwrap_or(pan_orbit.target_radius);
+                let direction = direction.clamp_length_max(1.0);
+                pan_orbit.target_focus +=
+                    (right * direction.x + back * direction.y) * rts.pan_speed * radius * dt;
+            }
+        }
+
+        let limits = pan_orbit.limits.for_projection(projection, &zoom_bounds);
+        let pitch = match (limits.zoom_lower, limits.zoom_upper) {
+            (Some(lower), Some(upper)) => {
+                rts.pitch_for_radius(pan_orbit.target_radius, lower, upper)
+            }
+            _ => rts.pitch_zoomed_in,
+        };
+        if pan_orbit.target_beta != pitch {
+            pan_orbit.target_beta = pitch;
+        }
+    }
+}
+
+#[cfg(test)]
+mod pitch_for_radius_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn interpolates_between_zoom_limits() {
+        let rts = RtsCameraController {
+            pitch_zoomed_in: 0.5,
+            pitch_zoomed_out: 1.5,
+            ..default()
+        };
+        assert!(approx_eq!(f32, rts.pitch_for_radius(5.0, 5.0, 15.0), 0.5));
+        assert!(approx_eq!(f32, rts.pitch_for_radius(10.0, 5.0, 15.0), 1.0));
+        assert!(approx_eq!(f32, rts.pitch_for_radius(20.0, 5.0, 15.0), 1.5));
+    }
+
+    #[test]
+    fn invalid_limits_use_zoomed_in_pitch() {
+        let rts = RtsCameraController::default();
+        assert_eq!(rts.pitch_for_radius(10.0, 5.0, 5.0), rts.pitch_zoomed_in);
+    }
+}
@@ -0,0 +1,705 @@
+use crate::{
+    HorizontalScroll, KeyChord, MotionScale, PanMode, PanOrbitCamera, SimultaneousDragBehavior,
+    TouchpadRotateMode, TouchpadScroll,
+};
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+/// Settings for orbiting a `PanOrbitCamera`, in `PanOrbitCamera::orbit`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct OrbitSettings {
+    /// If `false`, user input can't orbit the camera, e.g. for a viewer that only allows panning
+    /// and zooming. `PanOrbitCommand`s and changes to `target_alpha` and `target_beta` still work.
+    /// Defaults to `true`.
+    pub enabled: bool,
+    /// The sensitivity of the orbiting motion. Defaults to `1.0`.
+    pub sensitivity: f32,
+    /// How much smoothing is applied to the orbit motion. A value of `0.0` disables smoothing, so
+    /// there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite smoothing.
+    /// Defaults to `0.8`.
+    pub smoothness: f32,
+    /// The sensitivity of rolling with `bindings.button_roll`. Defaults to `1.0`.
+    pub roll_sensitivity: f32,
+    /// Whether to invert the horizontal orbit direction. Defaults to `false`.
+    pub invert_x: bool,
+    /// Whether to invert the vertical orbit direction. Defaults to `false`.
+    pub invert_y: bool,
+    /// If `true`, user input will not change `alpha`, so the camera can only orbit vertically.
+    /// `target_alpha` can still be changed programmatically. Defaults to `false`.
+    pub lock_alpha: bool,
+    /// If `true`, user input will not change `beta`, so the camera can only orbit horizontally,
+    /// e.g. for a turntable viewer. `target_beta` can still be changed programmatically. Defaults
+    /// to `false`.
+    pub lock_beta: bool,
+    /// Whether to allow the camera to go upside down. Defaults to `false`.
+    pub allow_upside_down: bool,
+    /// If `true`, dragging sets the angular velocity of the orbit instead of rotating the camera
+    /// directly. The further you drag from where you started, the faster the camera rotates,
+    /// and it keeps rotating at that speed until you release the button. Touchpad gestures still
+    /// rotate the camera directly. Useful for coarse navigation, e.g. in planet-scale scenes.
+    /// Dragging across the full width of the window (see `motion_scale`) adds a rotation speed
+    /// of one full turn per second (before applying `sensitivity`).
+    /// Defaults to `false`.
+    pub velocity_mode: bool,
+    /// The maximum orbit speed in radians per second when `velocity_mode` is enabled.
+    /// Defaults to `PI` (half a turn per second).
+    pub max_velocity: f32,
+    /// If `true`, `alpha` is kept within `[0, TAU)`, so it doesn't grow indefinitely as the
+    /// camera spins around. `target_alpha` is shifted along with it, so if you set `target_alpha`
+    /// to an absolute angle, use `set_target_alpha_wrapped` rather than setting it directly.
+    /// Has no effect if either alpha limit is set.
+    /// Defaults to `false`.
+    pub normalize_alpha: bool,
+    /// If set, `target_alpha` snaps to the nearest multiple of this angle (in radians) when the
+    /// orbit button is released, e.g. `PI / 4.0` for 45 degree increments. The camera then
+    /// smoothly animates to the snapped angle. Useful for isometric games and architectural
+    /// viewers. Defaults to `None`.
+    pub alpha_snap: Option<f32>,
+    /// If set, `target_beta` snaps to the nearest multiple of this angle (in radians) when the
+    /// orbit button is released. Defaults to `None`.
+    pub beta_snap: Option<f32>,
+    /// If set, alpha, beta, and roll never change faster than this many radians per second,
+    /// after smoothing, so spikes in input can't whip the camera around. Unlike `max_velocity`,
+    /// this applies to all orbiting, not just `velocity_mode`.
+    /// Defaults to `None`.
+    pub max_speed: Option<f32>,
+    /// How much the touchpad rotate gesture rotates the camera, before applying `sensitivity` or
+    /// `roll_sensitivity`. Defaults to `3.0`.
+    pub touchpad_rotate_multiplier: f32,
+    /// Which dimensions orbit motion is normalized by. See `MotionScale` for details.
+    /// Defaults to `MotionScale::Window`.
+    pub motion_scale: MotionScale,
+    /// When `allow_upside_down` is `true`, how many radians beta has to go past straight up or
+    /// straight down before `PanOrbitCamera::is_upside_down` changes, so the horizontal orbit
+    /// direction doesn't flip back and forth while orbiting near the poles. It never changes while
+    /// the user is dragging to orbit, so the direction stays the same for the whole gesture.
+    /// Defaults to `0.1`.
+    pub upside_down_hysteresis: f32,
+}
+
+impl Default for OrbitSettings {
+    fn default() -> Self {
+        OrbitSettings {
+            enabled: true,
+            sensitivity: 1.0,
+            smoothness: 0.8,
+            roll_sensitivity: 1.0,
+            invert_x: false,
+            invert_y: false,
+            lock_alpha: false,
+            lock_beta: false,
+            allow_upside_down: false,
+            velocity_mode: false,
+            max_velocity: PI,
+            normalize_alpha: false,
+            alpha_snap: None,
+            beta_snap: None,
+            max_speed: None,
+            touchpad_rotate_multiplier: 3.0,
+            motion_scale: MotionScale::Window,
+            upside_down_hysteresis: 0.1,
+        }
+    }
+}
+
+/// Settings for panning a `PanOrbitCamera`, in `PanOrbitCamera::pan`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct PanSettings {
+    /// If `false`, user input can't pan the camera, e.g. for a product viewer that only allows
+    /// orbiting and zooming. Defaults to `true`.
+    pub enabled: bool,
+    /// The sensitivity of the panning motion. Defaults to `1.0`.
+    pub sensitivity: f32,
+    /// How much smoothing is applied to the panning motion. A value of `0.0` disables smoothing, so
+    /// there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite smoothing.
+    /// Defaults to `0.6`.
+    pub smoothness: f32,
+    /// Which axes panning moves the focus along. Defaults to `PanMode::CameraRelative`.
+    pub mode: PanMode,
+    /// If set, the camera pans when the cursor is within this many logical pixels of the edge of
+    /// the viewport, in the direction of that edge, like in RTS games. Edge panning is disabled
+    /// while orbiting. Defaults to `None` (no edge panning).
+    pub edge_pan_margin: Option<f32>,
+    /// How fast edge panning pans, in logical pixels per second, i.e. the same distance as dragging
+    /// the mouse that far. Defaults to `500.0`.
+    pub edge_pan_speed: f32,
+    /// If set, the focus never moves faster than this many world units per second, after
+    /// smoothing. Defaults to `None`.
+    pub max_speed: Option<f32>,
+    /// If set, panning by dragging has momentum, so flicking the view keeps it moving after the
+    /// button is released, as in map apps. The value is how quickly it slows down, as a decay rate
+    /// per second, so higher values stop sooner. `4.0` is a good starting point. This moves the
+    /// target focus, so it works independently of `smoothness`.
+    /// Defaults to `None` (no momentum).
+    pub kinetic_friction: Option<f32>,
+    /// If `true`, pressing any of the camera's mouse buttons stops the pan momentum, so the view
+    /// can be caught while it is still moving. Defaults to `true`.
+    pub stop_on_press: bool,
+    /// How fast `bindings.key_move_forward` and `bindings.key_move_back` move the focus, as a
+    /// multiple of the radius per second. Defaults to `1.0`.
+    pub key_move_speed: f32,
+}
+
+impl Default for PanSettings {
+    fn default() -> Self {
+        PanSettings {
+            enabled: true,
+            sensitivity: 1.0,
+            smoothness: 0.6,
+            mode: PanMode::CameraRelative,
+            edge_pan_margin: None,
+            edge_pan_speed: 500.0,
+            max_speed: None,
+            kinetic_friction: None,
+            stop_on_press: true,
+            key_move_speed: 1.0,
+        }
+    }
+}
+
+/// Settings for zooming a `PanOrbitCamera`, in `PanOrbitCamera::zoom`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct ZoomSettings {
+    /// If `false`, user input can't zoom the camera. Defaults to `true`.
+    pub enabled: bool,
+    /// The sensitivity of moving the camera closer or further way using the scroll wheel. Defaults
+    /// to `1.0`.
+    pub sensitivity: f32,
+    /// How much smoothing is applied to the zoom motion. A value of `0.0` disables smoothing, so
+    /// there's a 1:1 mapping of input to camera position. A value of `1.0` is infinite smoothing.
+    /// Defaults to `0.8`. Note that this setting does not apply to pixel-based scroll events, as
+    /// they are typically already smooth. It only applies to line-based scroll events.
+    pub smoothness: f32,
+    /// Whether to reverse the zoom direction. Defaults to `false`.
+    pub reversed: bool,
+    /// If `true`, zooming keeps the point under the cursor fixed on screen, by moving the focus as
+    /// the zoom changes. Currently only applies to orthographic cameras. Defaults to `false`.
+    pub to_cursor: bool,
+    /// If `true`, touchpad pinch gestures zoom towards the point under the cursor, as in map and
+    /// design apps on macOS, by moving the focus as the zoom changes. Unlike `to_cursor`, this
+    /// applies to perspective cameras too, where the point is on the plane through the focus,
+    /// perpendicular to the view direction. Defaults to `false`.
+    pub pinch_to_cursor: bool,
+    /// How much each line of scroll wheel input zooms, as a fraction of the current zoom value.
+    /// Ignored if `step_distance` is set. Defaults to `0.2`.
+    pub step: f32,
+    /// If set, pixel-based zoom gestures (e.g. trackpad pinch) have momentum, so the zoom continues
+    /// briefly after the gesture ends. The value is how quickly the zoom slows down, as a decay
+    /// rate per second, so higher values stop sooner. `5.0` is a good starting point. Defaults to
+    /// `None` (no momentum).
+    pub kinetic_friction: Option<f32>,
+    /// If set, the radius (or scale, for orthographic cameras) never changes faster than this
+    /// fraction of its current value per second, after smoothing, e.g. `2.0` allows it to
+    /// double or shrink by up to twice itself each second. Defaults to `None`.
+    pub max_speed: Option<f32>,
+    /// If set, each line of scroll wheel input zooms by exactly this amount, i.e. this distance
+    /// in world units for perspective cameras, or this amount of `scale` for orthographic cameras.
+    /// Useful for precision apps where each wheel click should move a known distance.
+    /// Defaults to `None` (use `step`).
+    pub step_distance: Option<f32>,
+    /// How fast `bindings.key_zoom_in` and `bindings.key_zoom_out` zoom, in lines of scroll wheel
+    /// input per second. Defaults to `5.0`.
+    pub key_speed: f32,
+    /// If `true`, zooming will first move the focus point along the view direction so that it
+    /// sits at the depth of the surface under the cursor, keeping `radius` meaningful. Without
+    /// this, the focus can end up far behind (or in front of) the object you are looking at,
+    /// making zoom either very slow or very fast.
+    /// Surfaces are detected by casting a ray against the `Aabb`s of entities, so this is
+    /// approximate for non-box shaped meshes. Only applies to perspective cameras.
+    /// Defaults to `false`.
+    pub auto_depth: bool,
+}
+
+impl Default for ZoomSettings {
+    fn default() -> Self {
+        ZoomSettings {
+            enabled: true,
+            sensitivity: 1.0,
+            smoothness: 0.8,
+            reversed: false,
+            to_cursor: false,
+            pinch_to_cursor: false,
+            step: 0.2,
+            kinetic_friction: None,
+            max_speed: None,
+            step_distance: None,
+            key_speed: 5.0,
+            auto_depth: false,
+        }
+    }
+}
+
+/// Limits on the values of a `PanOrbitCamera`, in `PanOrbitCamera::limits`. Limits only restrict
+/// user input and the target values, and are applied every frame.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct Limits {
+    /// Upper limit on the `alpha` value, in radians. Use this to restrict the maximum rotation
+    /// around the global Y axis. Defaults to `None`.
+    pub alpha_upper: Option<f32>,
+    /// Lower limit on the `alpha` value, in radians. Use this to restrict the maximum rotation
+    /// around the global Y axis. Defaults to `None`.
+    pub alpha_lower: Option<f32>,
+    /// Upper limit on the `beta` value, in radians. Use this to restrict the maximum rotation
+    /// around the local X axis. Defaults to `None`.
+    pub beta_upper: Option<f32>,
+    /// Lower limit on the `beta` value, in radians. Use this to restrict the maximum rotation
+    /// around the local X axis. Defaults to `None`.
+    pub beta_lower: Option<f32>,
+    /// Upper limit on the zoom. This applies to `radius`, in the case of using a perspective
+    /// camera, or the projection scale in the case of using an orthographic camera. Note that the
+    /// zoom value (radius or scale) will never go below
+    /// `PanOrbitZoomBounds::min_zoom_distance`. Defaults to `None`.
+    pub zoom_upper: Option<f32>,
+    /// Lower limit on the zoom. This applies to `radius`, in the case of using a perspective
+    /// camera, or the projection scale in the case of using an orthographic camera. If `None`,
+    /// perspective cameras can't zoom closer than their near plane times
+    /// `PanOrbitZoomBounds::near_plane_zoom_factor`, so the focus isn't clipped. Note that the
+    /// zoom value (radius or scale) will never go below
+    /// `PanOrbitZoomBounds::min_zoom_distance`. Defaults to `None`.
+    pub zoom_lower: Option<f32>,
+    /// Upper limits on the X, Y, and Z positions of the camera focus point. Defaults to
+    /// `[None; 3]`.
+    pub focus_upper: [Option<f32>; 3],
+    /// Lower limits on the X, Y, and Z positions of the camera focus point. Defaults to
+    /// `[None; 3]`.
+    pub focus_lower: [Option<f32>; 3],
+    /// How far the camera can be dragged past its alpha, beta, zoom, and focus limits, as a
+    /// fraction of the drag distance beyond the limit. When the drag ends, the camera springs back
+    /// to the limit. A value of `0.0` means the limits are hard, and there is no overshoot.
+    /// Defaults to `0.0`.
+    pub elasticity: f32,
+    /// When `orbit.allow_upside_down` is `false`, beta is stopped this many radians before
+    /// straight up or straight down (±90 degrees), which avoids the camera flipping or rolling at
+    /// the poles. Defaults to `0.0001`.
+    pub beta_pole_padding: f32,
+    /// If set, the camera is kept above this Y position (the ground), by raising the lower limit
+    /// on beta depending on the radius and the focus height, so the camera never dips below the
+    /// ground no matter how far it's zoomed out. This assumes the default
+    /// `PanOrbitCamera::orientation_offset`.
+    /// Defaults to `None`.
+    pub stay_above: Option<f32>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            alpha_upper: None,
+            alpha_lower: None,
+            beta_upper: None,
+            beta_lower: None,
+            zoom_upper: None,
+            zoom_lower: None,
+            focus_upper: [None; 3],
+            focus_lower: [None; 3],
+            elasticity: 0.0,
+            beta_pole_padding: 0.0001,
+            stay_above: None,
+        }
+    }
+}
+
+/// The mouse buttons and keys that control a `PanOrbitCamera`, in `PanOrbitCamera::bindings`.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct Bindings {
+    /// Button used to orbit the camera. Defaults to `Button::Left`.
+    pub button_orbit: MouseButton,
+    /// Button used to pan the camera. Defaults to `Button::Right`.
+    pub button_pan: MouseButton,
+    /// Button used to zoom the camera by dragging vertically (dolly), as in e.g. Maya. Dragging up
+    /// zooms in, dragging down zooms out. This uses `zoom.sensitivity` and `zoom.smoothness`, same
+    /// as the scroll wheel. Defaults to `None` (no drag zoom).
+    pub button_zoom: Option<MouseButton>,
+    /// Button used to roll the camera around the view axis by dragging horizontally. Defaults to
+    /// `None` (no roll control).
+    pub button_roll: Option<MouseButton>,
+    /// Key that must be pressed for `button_orbit` to work. Defaults to `None` (no modifier).
+    pub modifier_orbit: Option<KeyCode>,
+    /// Key that must be pressed for `button_pan` to work. Defaults to `None` (no modifier).
+    pub modifier_pan: Option<KeyCode>,
+    /// Key that must be pressed for `button_zoom` to work. Defaults to `None` (no modifier).
+    pub modifier_zoom: Option<KeyCode>,
+    /// Keys that must all be held for `button_orbit` to work, in addition to `modifier_orbit`, e.g.
+    /// `KeyChord::new(&[KeyCode::ControlLeft, KeyCode::ShiftLeft])`. When the modifiers of several
+    /// buttons are held, the button with the most specific modifiers wins. Defaults to an empty
+    /// chord.
+    pub chord_orbit: KeyChord,
+    /// Keys that must all be held for `button_pan` to work, in addition to `modifier_pan`. Defaults
+    /// to an empty chord.
+    pub chord_pan: KeyChord,
+    /// Keys that must all be held for `button_zoom` to work, in addition to `modifier_zoom`.
+    /// Defaults to an empty chord.
+    pub chord_zoom: KeyChord,
+    /// If `true`, a button only works if no modifier keys (Shift, Ctrl, Alt, Super) other than its
+    /// own are held, e.g. so plain left mouse orbiting doesn't also happen while Ctrl is held for
+    /// selection. Defaults to `false`.
+    pub exclusive_modifiers: bool,
+    /// Key that re-centers the camera on the point given by the registered `FocusProvider`. Only
+    /// has an effect when `FocusProviderPlugin` has been added. Defaults to `None`.
+    pub key_recenter: Option<KeyCode>,
+    /// Key that zooms in continuously while held, for devices without a scroll wheel. Defaults to
+    /// `None`.
+    pub key_zoom_in: Option<KeyCode>,
+    /// Key that zooms out continuously while held. Defaults to `None`.
+    pub key_zoom_out: Option<KeyCode>,
+    /// Key that, while held, makes the mouse wheel pan vertically instead of zooming, e.g.
+    /// `Some(KeyCode::ShiftLeft)` as in many 2D tools. Defaults to `None`.
+    pub modifier_wheel_pan_vertical: Option<KeyCode>,
+    /// Key that, while held, makes the mouse wheel pan horizontally instead of zooming. Defaults to
+    /// `None`.
+    pub modifier_wheel_pan_horizontal: Option<KeyCode>,
+    /// Key that, while held, makes the mouse wheel orbit horizontally instead of zooming. Defaults
+    /// to `None`.
+    pub modifier_wheel_orbit: Option<KeyCode>,
+    /// What two-finger scrolling on a touchpad does when neither `modifier_orbit_touchpad` nor
+    /// `modifier_pan_touchpad` is held. Three-finger drags aren't reported separately by the
+    /// platform, but when macOS's "three finger drag" accessibility setting is on, they arrive as
+    /// left button drags, so they orbit with the default `button_orbit`. Defaults to
+    /// `TouchpadScroll::Orbit` on macOS, as in most 3D apps there, and `TouchpadScroll::Pan`
+    /// elsewhere.
+    pub touchpad_scroll: TouchpadScroll,
+    /// Key that, while held, makes touchpad scrolling orbit the camera. Defaults to `None` (no
+    /// modifier).
+    pub modifier_orbit_touchpad: Option<KeyCode>,
+    /// Key that, while held, makes touchpad scrolling pan the camera. Takes priority over
+    /// `modifier_orbit_touchpad`. Defaults to `Some(KeyCode::ShiftLeft)` on macOS, so Shift
+    /// switches from orbiting to panning, and `None` (no modifier) elsewhere.
+    pub modifier_pan_touchpad: Option<KeyCode>,
+    /// Key that must be held when releasing the orbit button for `orbit.alpha_snap` and
+    /// `orbit.beta_snap` to take effect. If `None`, snapping always happens. Defaults to `None`.
+    pub modifier_snap: Option<KeyCode>,
+    /// Key that, while held, scales down the orbit, pan, and zoom sensitivity by
+    /// `precision_multiplier`, for fine adjustments. Defaults to `None`.
+    pub modifier_precision: Option<KeyCode>,
+    /// How much to multiply the sensitivity by while `modifier_precision` is held.
+    /// Defaults to `0.25`.
+    pub precision_multiplier: f32,
+    /// Key that moves the focus forward along the view direction while held, without changing
+    /// the radius, so the camera travels through the scene rather than zooming in.
+    /// Defaults to `None`.
+    pub key_move_forward: Option<KeyCode>,
+    /// Key that moves the focus backward along the view direction while held.
+    /// Defaults to `None`.
+    pub key_move_back: Option<KeyCode>,
+    /// What happens when `button_orbit` and `button_pan` are both held, e.g. to dolly
+    ///  by dragging with both buttons as in 3ds Max. Only applies if they are different
+    /// buttons. Defaults to `SimultaneousDragBehavior::PreferOrbit`.
+    pub simultaneous_drag: SimultaneousDragBehavior,
+    /// What horizontal scrolling with a regular mouse wheel does, e.g. from tilting the wheel.
+    /// Horizontal scrolling on a touchpad pans or orbits along with vertical scrolling instead.
+    /// Defaults to `HorizontalScroll::Pan`.
+    pub horizontal_scroll: HorizontalScroll,
+    /// What the touchpad rotate (twist) gesture controls. Defaults to `TouchpadRotateMode::Alpha`.
+    pub touchpad_rotate_mode: TouchpadRotateMode,
+    /// How far the mouse must move after pressing a button, in pixels, before it counts as a
+    /// drag. Until then, the mouse movement is ignored, so small jitters while clicking don't
+    /// move the camera. This is useful when orbiting shares a button with selection. Releasing
+    /// the orbit or pan button before reaching the threshold sends a `ClickedWithoutDrag` event,
+    /// so with the default, releasing it without moving the mouse at all counts as a click.
+    /// Defaults to `0.0`.
+    pub drag_threshold: f32,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        Bindings {
+            button_orbit: MouseButton::Left,
+            button_pan: MouseButton::Right,
+            button_zoom: None,
+            button_roll: None,
+            modifier_orbit: None,
+            modifier_pan: None,
+            modifier_zoom: None,
+            chord_orbit: KeyChord::default(),
+            chord_pan: KeyChord::default(),
+            chord_zoom: KeyChord::default(),
+            exclusive_modifiers: false,
+            key_recenter: None,
+            key_zoom_in: None,
+            key_zoom_out: None,
+            modifier_wheel_pan_vertical: None,
+            modifier_wheel_pan_horizontal: None,
+            modifier_wheel_orbit: None,
+            touchpad_scroll: TouchpadScroll::default(),
+            modifier_orbit_touchpad: None,
+            modifier_pan_touchpad: cfg!(target_os = "macos").then_some(KeyCode::ShiftLeft),
+            modifier_snap: None,
+            modifier_precision: None,
+            precision_multiplier: 0.25,
+            key_move_forward: None,
+            key_move_back: None,
+            simultaneous_drag: SimultaneousDragBehavior::PreferOrbit,
+            horizontal_scroll: HorizontalScroll::Pan,
+            touchpad_rotate_mode: TouchpadRotateMode::Alpha,
+            drag_threshold: 0.0,
+        }
+    }
+}
+
+impl Bindings {
+    /// Blender-style bindings, where the middle mouse button does everything: drag to orbit,
+    /// Shift+drag to pan, and Ctrl+drag to zoom. The other bindings are left at their defaults.
+    /// # Example
+    /// ```
+    /// # use bevy_panorbit_camera::{Bindings, PanOrbitCamera};
+    /// let mut camera = PanOrbitCamera::default();
+    /// camera.bindings = Bindings::blender();
+    /// ```
+    pub fn blender() -> Self {
+        Bindings {
+            button_orbit: MouseButton::Middle,
+            button_pan: MouseButton::Middle,
+            button_zoom: Some(MouseButton::Middle),
+            modifier_orbit: None,
+            modifier_pan: Some(KeyCode::ShiftLeft),
+            modifier_zoom: Some(KeyCode::ControlLeft),
+            ..default()
+        }
+    }
+
+    /// Scroll wheel bindings common in 2D tools, such as image editors and map tools: the wheel
+    /// zooms, Shift+wheel pans vertically, and Ctrl+wheel pans horizontally. The other bindings
+    /// are left at their defaults.
+    pub fn wheel_pans() -> Self {
+        Bindings {
+            modifier_wheel_pan_vertical: Some(KeyCode::ShiftLeft),
+            modifier_wheel_pan_horizontal: Some(KeyCode::ControlLeft),
+            ..default()
+        }
+    }
+}
+
+impl PanOrbitCamera {
+    /// Create a camera from grouped settings, with every other field at its default value.
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_panorbit_camera::{Bindings, Limits, OrbitSettings, PanOrbitCamera, PanSettings, ZoomSettings};
+    /// let camera = PanOrbitCamera::from_settings(
+    ///     OrbitSettings { invert_x: true, ..default() },
+    ///     PanSettings::default(),
+    ///     ZoomSettings { reversed: true, ..default() },
+    ///     Limits { zoom_lower: Some(1.0), ..default() },
+    ///     Bindings { button_orbit: MouseButton::Right, button_pan: MouseButton::Middle, ..default() },
+    /// );
+    /// assert!(camera.orbit.invert_x);
+    /// assert_eq!(camera.limits.zoom_lower, Some(1.0));
+    /// ```
+    pub fn from_settings(
+        orbit: OrbitSettings,
+        pan: PanSettings,
+        zoom: ZoomSettings,
+        limits: Limits,
+        bindings: Bindings,
+    ) -> Self {
+        PanOrbitCamera {
+            orbit,
+            pan,
+            zoom,
+            limits,
+            bindings,
+            ..default()
+        }
+    }
+}
+
+/// Generates a deprecated getter and setter for each setting that used to be a field of
+/// `PanOrbitCamera`, before the settings were grouped, so code written against the flat fields
+/// keeps working
+macro_rules! flat_settings {
+    ($(
+        $getter:ident, $setter:ident: $ty:ty => $group:ident.$field:ident$([$index:literal])?;
+    )*) => {
+        impl PanOrbitCamera {
+            $(
+                #[doc = concat!(
+                    "The value of `", stringify!($group), ".", stringify!($field),
+                    $("[", stringify!($index), "]",)? "`."
+                )]
+                #[deprecated(note = "use the grouped settings fields instead")]
+                pub fn $getter(&self) -> $ty {
+                    self.$group.$field$([$index])?
+                }
+
+                #[doc = concat!(
+                    "Set `", stringify!($group), ".", stringify!($field),
+                    $("[", stringify!($index), "]",)? "`."
+                )]
+                #[deprecated(note = "use the grouped settings fields instead")]
+                pub fn $setter(&mut self, value: $ty) {
+                    self.$group.$field$([$index])? = value;
+                }
+            )*
+        }
+    };
+}
+
+flat_settings! {
+    alpha_upper_limit, set_alpha_upper_limit: Option<f32> => limits.alpha_upper;
+    alpha_lower_limit, set_alpha_lower_limit: Option<f32> => limits.alpha_lower;
+    beta_upper_limit, set_beta_upper_limit: Option<f32> => limits.beta_upper;
+    beta_lower_limit, set_beta_lower_limit: Option<f32> => limits.beta_lower;
+    zoom_upper_limit, set_zoom_upper_limit: Option<f32> => limits.zoom_upper;
+    zoom_lower_limit, set_zoom_lower_limit: Option<f32> => limits.zoom_lower;
+    limit_elasticity, set_limit_elasticity: f32 => limits.elasticity;
+    focus_x_upper_limit, set_focus_x_upper_limit: Option<f32> => limits.focus_upper[0];
+    focus_x_lower_limit, set_focus_x_lower_limit: Option<f32> => limits.focus_lower[0];
+    focus_y_upper_limit, set_focus_y_upper_limit: Option<f32> => limits.focus_upper[1];
+    focus_y_lower_limit, set_focus_y_lower_limit: Option<f32> => limits.focus_lower[1];
+    focus_z_upper_limit, set_focus_z_upper_limit: Option<f32> => limits.focus_upper[2];
+    focus_z_lower_limit, set_focus_z_lower_limit: Option<f32> => limits.focus_lower[2];
+    beta_pole_padding, set_beta_pole_padding: f32 => limits.beta_pole_padding;
+    stay_above, set_stay_above: Option<f32> => limits.stay_above;
+    orbit_enabled, set_orbit_enabled: bool => orbit.enabled;
+    orbit_sensitivity, set_orbit_sensitivity: f32 => orbit.sensitivity;
+    orbit_smoothness, set_orbit_smoothness: f32 => orbit.smoothness;
+    roll_sensitivity, set_roll_sensitivity: f32 => orbit.roll_sensitivity;
+    invert_orbit_x, set_invert_orbit_x: bool => orbit.invert_x;
+    invert_orbit_y, set_invert_orbit_y: bool => orbit.invert_y;
+    lock_alpha, set_lock_alpha: bool => orbit.lock_alpha;
+    lock_beta, set_lock_beta: bool => orbit.lock_beta;
+    allow_upside_down, set_allow_upside_down: bool => orbit.allow_upside_down;
+    orbit_velocity_mode, set_orbit_velocity_mode: bool => orbit.velocity_mode;
+    orbit_max_speed, set_orbit_max_speed: f32 => orbit.max_velocity;
+    normalize_alpha, set_normalize_alpha: bool => orbit.normalize_alpha;
+    alpha_snap, set_alpha_snap: Option<f32> => orbit.alpha_snap;
+    beta_snap, set_beta_snap: Option<f32> => orbit.beta_snap;
+    max_orbit_speed, set_max_orbit_speed: Option<f32> => orbit.max_speed;
+    touchpad_rotate_multiplier, set_touchpad_rotate_multiplier: f32 =>
+        orbit.touchpad_rotate_multiplier;
+    motion_scale, set_motion_scale: MotionScale => orbit.motion_scale;
+    upside_down_hysteresis, set_upside_down_hysteresis: f32 => orbit.upside_down_hysteresis;
+    pan_enabled, set_pan_enabled: bool => pan.enabled;
+    pan_sensitivity, set_pan_sensitivity: f32 => pan.sensitivity;
+    pan_smoothness, set_pan_smoothness: f32 => pan.smoothness;
+    pan_mode, set_pan_mode: PanMode => pan.mode;
+    edge_pan_margin, set_edge_pan_margin: Option<f32> => pan.edge_pan_margin;
+    edge_pan_speed, set_edge_pan_speed: f32 => pan.edge_pan_speed;
+    max_pan_speed, set_max_pan_speed: Option<f32> => pan.max_speed;
+    pan_kinetic_friction, set_pan_kinetic_friction: Option<f32> => pan.kinetic_friction;
+    pan_stop_on_press, set_pan_stop_on_press: bool => pan.stop_on_press;
+    key_move_speed, set_key_move_speed: f32 => pan.key_move_speed;
+    zoom_enabled, set_zoom_enabled: bool => zoom.enabled;
+    zoom_sensitivity, set_zoom_sensitivity: f32 => zoom.sensitivity;
+    zoom_smoothness, set_zoom_smoothness: f32 => zoom.smoothness;
+    reversed_zoom, set_reversed_zoom: bool => zoom.reversed;
+    zoom_to_cursor, set_zoom_to_cursor: bool => zoom.to_cursor;
+    pinch_to_cursor, set_pinch_to_cursor: bool => zoom.pinch_to_cursor;
+    zoom_step, set_zoom_step: f32 => zoom.step;
+    zoom_kinetic_friction, set_zoom_kinetic_friction: Option<f32> => zoom.kinetic_friction;
+    max_zoom_speed, set_max_zoom_speed: Option<f32> => zoom.max_speed;
+    zoom_step_distance, set_zoom_step_distance: Option<f32> => zoom.step_distance;
+    key_zoom_speed, set_key_zoom_speed: f32 => zoom.key_speed;
+    zoom_auto_depth, set_zoom_auto_depth: bool => zoom.auto_depth;
+    button_orbit, set_button_orbit: MouseButton => bindings.button_orbit;
+    button_pan, set_button_pan: MouseButton => bindings.button_pan;
+    button_zoom, set_button_zoom: Option<MouseButton> => bindings.button_zoom;
+    button_roll, set_button_roll: Option<MouseButton> => bindings.button_roll;
+    modifier_orbit, set_modifier_orbit: Option<KeyCode> => bindings.modifier_orbit;
+    modifier_pan, set_modifier_pan: Option<KeyCode> => bindings.modifier_pan;
+    modifier_zoom, set_modifier_zoom: Option<KeyCode> => bindings.modifier_zoom;
+    chord_orbit, set_chord_orbit: KeyChord => bindings.chord_orbit;
+    chord_pan, set_chord_pan: KeyChord => bindings.chord_pan;
+    chord_zoom, set_chord_zoom: KeyChord => bindings.chord_zoom;
+    exclusive_modifiers, set_exclusive_modifiers: bool => bindings.exclusive_modifiers;
+    key_recenter, set_key_recenter: Option<KeyCode> => bindings.key_recenter;
+    key_zoom_in, set_key_zoom_in: Option<KeyCode> => bindings.key_zoom_in;
+    key_zoom_out, set_key_zoom_out: Option<KeyCode> => bindings.key_zoom_out;
+    modifier_wheel_pan_vertical, set_modifier_wheel_pan_vertical: Option<KeyCode> =>
+        bindings.modifier_wheel_pan_vertical;
+    modifier_wheel_pan_horizontal, set_modifier_wheel_pan_horizontal: Option<KeyCode> =>
+        bindings.modifier_wheel_pan_horizontal;
+    modifier_wheel_orbit, set_modifier_wheel_orbit: Option<KeyCode> =>
+        bindings.modifier_wheel_orbit;
+    touchpad_scroll, set_touchpad_scroll: TouchpadScroll => bindings.touchpad_scroll;
+    modifier_orbit_touchpad, set_modifier_orbit_touchpad: Option<KeyCode> =>
+        bindings.modifier_orbit_touchpad;
+    modifier_pan_touchpad, set_modifier_pan_touchpad: Option<KeyCode> =>
+        bindings.modifier_pan_touchpad;
+    modifier_snap, set_modifier_snap: Option<KeyCode> => bindings.modifier_snap;
+    precision_modifier, set_precision_modifier: Option<KeyCode> => bindings.modifier_precision;
+    precision_multiplier, set_precision_multiplier: f32 => bindings.precision_multiplier;
+    key_move_forward, set_key_move_forward: Option<KeyCode> => bindings.key_move_forward;
+    key_move_back, set_key_move_back: Option<KeyCode> => bindings.key_move_back;
+    simultaneous_drag, set_simultaneous_drag: SimultaneousDragBehavior =>
+        bindings.simultaneous_drag;
+    horizontal_scroll, set_horizontal_scroll: HorizontalScroll => bindings.horizontal_scroll;
+    touchpad_rotate_mode, set_touchpad_rotate_mode: TouchpadRotateMode =>
+        bindings.touchpad_rotate_mode;
+    drag_threshold, set_drag_threshold: f32 => bindings.drag_threshold;
+}
+
+#[cfg(test)]
+mod settings_tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_camera_defaults() {
+        let camera = PanOrbitCamera::from_settings(
+            OrbitSettings::default(),
+            PanSettings::default(),
+            ZoomSettings::default(),
+            Limits::default(),
+            Bindings::default(),
+        );
+        assert_eq!(camera, PanOrbitCamera::default());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn flat_accessors_use_groups() {
+        let mut camera = PanOrbitCamera::default();
+        camera.set_zoom_auto_depth(true);
+        camera.set_focus_y_lower_limit(Some(-2.0));
+        camera.set_precision_modifier(Some(KeyCode::AltLeft));
+        assert!(camera.zoom.auto_depth);
+        assert_eq!(camera.limits.focus_lower[1], Some(-2.0));
+        assert_eq!(camera.bindings.modifier_precision, Some(KeyCode::AltLeft));
+
+        camera.orbit.max_velocity = 1.5;
+        assert_eq!(camera.orbit_max_speed(), 1.5);
+        assert_eq!(camera.focus_x_lower_limit(), None);
+    }
+
+    #[test]
+    fn presets_only_change_bindings() {
+        let expected = [
+            (
+                Bindings::blender(),
+                Bindings {
+                    button_orbit: MouseButton::Middle,
+                    button_pan: MouseButton::Middle,
+                    button_zoom: Some(MouseButton::Middle),
+                    modifier_orbit: None,
+                    modifier_pan: Some(KeyCode::ShiftLeft),
+                    modifier_zoom: Some(KeyCode::ControlLeft),
+                    ..default()
+                },
+            ),
+            (
+                Bindings::wheel_pans(),
+                Bindings {
+                    modifier_wheel_pan_vertical: Some(KeyCode::ShiftLeft),
+                    modifier_wheel_pan_horizontal: Some(KeyCode::ControlLeft),
+                    ..default()
+                },
+            ),
+        ];
+        for (preset, bindings) in expected {
+            assert_eq!(preset, bindings);
+
+            let camera = PanOrbitCamera {
+                bindings: preset,
+                ..default()
+            };
+            assert_eq!(camera.orbit, OrbitSettings::default());
+            assert_eq!(camera.pan, PanSettings::default());
+            assert_eq!(camera.zoom, ZoomSettings::default());
+            assert_eq!(camera.limits, Limits::default());
+        }
+    }
+}
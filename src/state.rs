@@ -0,0 +1,166 @@
+use crate::util;
+use bevy::prelude::*;
+
+/// A plain snapshot of the values that define where a `PanOrbitCamera` is, without any of its
+/// settings. Useful for saving and restoring camera positions, or blending between them with
+/// `PanOrbitCamera::blend_between`. The update functions are the same plain math the camera uses,
+/// without any ECS state, so they can also be used by tools that don't run the plugin.
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[reflect(Default)]
+pub struct OrbitState {
+    /// The focus point.
+    pub focus: Vec3,
+    /// The alpha value, in radians.
+    pub alpha: f32,
+    /// The beta value, in radians.
+    pub beta: f32,
+    /// The radius.
+    pub radius: f32,
+}
+
+impl OrbitState {
+    /// Create an orbit state from the given values.
+    pub fn new(focus: Vec3, alpha: f32, beta: f32, radius: f32) -> Self {
+        OrbitState {
+            focus,
+            alpha,
+            beta,
+            radius,
+        }
+    }
+
+    /// Interpolate between two states, where `t` of `0.0` gives `self` and `1.0` gives `other`.
+    /// Alpha takes the shortest way around, so blending between e.g. 10 and 350 degrees goes
+    /// through 0 degrees.
+    pub fn lerp(&self, other: &OrbitState, t: f32) -> OrbitState {
+        OrbitState {
+            focus: self.focus.lerp(other.focus, t),
+            alpha: self.alpha + util::shortest_angle_delta(self.alpha, other.alpha) * t,
+            beta: self.beta + (other.beta - self.beta) * t,
+            radius: self.radius + (other.radius - self.radius) * t,
+        }
+    }
+
+    /// Orbit by `delta` radians, where `delta.x` is added to alpha and `delta.y` to beta.
+    pub fn apply_orbit(&self, delta: Vec2) -> OrbitState {
+        OrbitState {
+            alpha: self.alpha + delta.x,
+            beta: self.beta + delta.y,
+            ..*self
+        }
+    }
+
+    /// Move the focus by `delta` world units along the camera's right (`delta.x`) and up
+    /// (`delta.y`) axes, assuming the default `PanOrbitCamera::orientation_offset`.
+    pub fn apply_pan(&self, delta: Vec2) -> OrbitState {
+        let rotation = Quat::from_rotation_y(self.alpha) * Quat::from_rotation_x(-self.beta);
+        self.apply_pan_along(delta, rotation * Vec3::X, rotation * Vec3::Y)
+    }
+
+    /// Move the focus by `delta` world units along the given `right` (`delta.x`) and `up`
+    /// (`delta.y`) axes, e.g. for a camera with a different orientation or `PanMode`.
+    pub fn apply_pan_along(&self, delta: Vec2, right: Vec3, up: Vec3) -> OrbitState {
+        OrbitState {
+            focus: self.focus + right * delta.x + up * delta.y,
+            ..*self
+        }
+    }
+
+    /// Zoom by a fraction of the radius, where positive values zoom in, e.g. `0.2` reduces the
+    /// radius by 20%. The radius never goes below `0.05`.
+    pub fn apply_zoom(&self, amount: f32) -> OrbitState {
+        OrbitState {
+            radius: Self::zoom_value(self.radius, amount).max(0.05),
+            ..*self
+        }
+    }
+
+    /// Zoom a radius or orthographic scale by a fraction of itself, without any limits, like
+    /// `apply_zoom`.
+    pub(crate) fn zoom_value(value: f32, amount: f32) -> f32 {
+        value * (1.0 - amount)
+    }
+
+    /// Move towards `target` the same way `PanOrbitCamera` does with `SmoothingModel::Lerp`.
+    /// `smoothness` is defined per frame at 60 FPS, like `OrbitSettings::smoothness`, and each
+    /// value snaps to the target once it is within `snap_threshold` of it.
+    pub fn step_smoothing(
+        &self,
+        target: &OrbitState,
+        smoothness: f32,
+        snap_threshold: f32,
+        delta_seconds: f32,
+    ) -> OrbitState {
+        let smoothness = util::frame_smoothness(smoothness, delta_seconds);
+        let step =
+            |from: f32, to: f32| util::lerp_and_snap_f32(from, to, smoothness, snap_threshold);
+        OrbitState {
+            focus: util::lerp_and_snap_vec3(self.focus, target.focus, smoothness, snap_threshold),
+            alpha: step(self.alpha, target.alpha),
+            beta: step(self.beta, target.beta),
+            radius: step(self.radius, target.radius),
+        }
+    }
+}
+
+#[cfg(test)]
+mod lerp_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn interpolates_all_values() {
+        let a = OrbitState::new(Vec3::ZERO, 0.0, 0.0, 2.0);
+        let b = OrbitState::new(Vec3::X, 1.0, 0.5, 4.0);
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.focus, Vec3::new(0.5, 0.0, 0.0));
+        assert!(approx_eq!(f32, mid.alpha, 0.5));
+        assert!(approx_eq!(f32, mid.beta, 0.25));
+        assert!(approx_eq!(f32, mid.radius, 3.0));
+    }
+
+    #[test]
+    fn alpha_takes_shortest_path() {
+        let a = OrbitState::new(Vec3::ZERO, 0.1, 0.0, 1.0);
+        let b = OrbitState::new(Vec3::ZERO, 2.0 * PI - 0.1, 0.0, 1.0);
+        assert!(approx_eq!(f32, a.lerp(&b, 0.5).alpha, 0.0, epsilon = 1e-5));
+    }
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn orbit_and_zoom_change_only_their_values() {
+        let state = OrbitState::new(Vec3::ONE, 0.0, 0.0, 10.0);
+        let orbited = state.apply_orbit(Vec2::new(0.5, 0.25));
+        assert_eq!(orbited, OrbitState::new(Vec3::ONE, 0.5, 0.25, 10.0));
+        let zoomed = state.apply_zoom(0.2);
+        assert_eq!(zoomed, OrbitState::new(Vec3::ONE, 0.0, 0.0, 8.0));
+        assert_eq!(state.apply_zoom(2.0).radius, 0.05);
+    }
+
+    #[test]
+    fn pan_follows_camera_axes() {
+        // Looking along -X, so the camera's right is -Z
+        let state = OrbitState::new(Vec3::ZERO, std::f32::consts::FRAC_PI_2, 0.0, 5.0);
+        let panned = state.apply_pan(Vec2::new(1.0, 2.0));
+        assert!(panned.focus.abs_diff_eq(Vec3::new(0.0, 2.0, -1.0), 1e-5));
+    }
+
+    #[test]
+    fn smoothing_moves_towards_target_and_snaps() {
+        let state = OrbitState::new(Vec3::ZERO, 0.0, 0.0, 1.0);
+        let target = OrbitState::new(Vec3::X, 1.0, 1.0, 2.0);
+        let stepped = state.step_smoothing(&target, 0.5, 0.001, 1.0 / 60.0);
+        assert!(approx_eq!(f32, stepped.alpha, 0.5, epsilon = 1e-5));
+        assert!(approx_eq!(f32, stepped.radius, 1.5, epsilon = 1e-5));
+        assert_eq!(
+            state.step_smoothing(&target, 0.0, 0.001, 1.0 / 60.0),
+            target
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::PanOrbitCommand;
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+
+/// Insert this resource to show on-screen touch controls for the active `PanOrbitCamera`: a
+/// joystick in the bottom left corner of the window for panning, a joystick in the bottom right
+/// corner for orbiting, and zoom in/out buttons in between. The controls send `PanOrbitCommand`
+/// events, so they work with any camera setup, and don't depend on multi-touch gestures.
+/// The overlay is made of UI nodes that are spawned when the resource is inserted or changed,
+/// and despawned when it is removed.
+/// Requires the `touch_controls` feature.
+#[derive(Resource, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct PanOrbitTouchControls {
+    /// How fast the camera orbits when the orbit joystick is fully deflected, in radians per
+    /// second. Defaults to `2.0`.
+    pub orbit_speed: f32,
+    /// How fast the camera pans when the pan joystick is fully deflected, in world units per
+    /// second. Defaults to `5.0`.
+    pub pan_speed: f32,
+    /// How fast the camera zooms while a zoom button is held, in lines of scroll wheel input per
+    /// second. Defaults to `5.0`.
+    pub zoom_speed: f32,
+    /// The diameter of each joystick, in logical pixels. Defaults to `120.0`.
+    pub joystick_size: f32,
+    /// The distance from the edges of the window, in logical pixels. Defaults to `30.0`.
+    pub margin: f32,
+}
+
+impl Default for PanOrbitTouchControls {
+    fn default() -> Self {
+        PanOrbitTouchControls {
+            orbit_speed: 2.0,
+            pan_speed: 5.0,
+            zoom_speed: 5.0,
+            joystick_size: 120.0,
+            margin: 30.0,
+        }
+    }
+}
+
+/// The root UI node of the touch controls
+#[derive(Component)]
+pub(crate) struct TouchControlsRoot;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum JoystickKind {
+    Pan,
+    Orbit,
+}
+
+/// A virtual joystick, and the touch that is currently moving it
+#[derive(Component)]
+pub(crate) struct TouchJoystick {
+    kind: JoystickKind,
+    touch: Option<u64>,
+    deflection: Vec2,
+}
+
+/// The knob of a virtual joystick, which follows the touch
+#[derive(Component)]
+pub(crate) struct TouchJoystickKnob;
+
+/// A button that zooms while held, in (positive) or out (negative)
+#[derive(Component)]
+pub(crate) struct TouchZoomButton(f32);
+
+const BACKGROUND: Color = Color::rgba(1.0, 1.0, 1.0, 0.15);
+const FOREGROUND: Color = Color::rgba(1.0, 1.0, 1.0, 0.4);
+
+/// Spawns the overlay when the resource is inserted or changed, and despawns it when removed
+pub(crate) fn sync_touch_controls(
+    mut commands: Commands,
+    controls: Option<Res<PanOrbitTouchControls>>,
+    roots: Query<Entity, With<TouchControlsRoot>>,
+) {
+    if controls.as_ref().is_some_and(|c| !c.is_changed()) {
+        return;
+    }
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+    let Some(controls) = controls else {
+        return;
+    };
+
+    let size = Val::Px(controls.joystick_size);
+    let knob_size = controls.joystick_size * 0.4;
+    let joystick = |kind: JoystickKind| {
+        (
+            NodeBundle {
+                style: Style {
+                    width: size,
+                    height: size,
+                    ..default()
+                },
+                background_color: BACKGROUND.into(),
+                ..default()
+            },
+            TouchJoystick {
+                kind,
+                touch: None,
+                deflection: Vec2::ZERO,
+            },
+        )
+    };
+    let knob = || {
+        (
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(knob_size),
+                    height: Val::Px(knob_size),
+                    ..default()
+                },
+                background_color: FOREGROUND.into(),
+                ..default()
+            },
+            TouchJoystickKnob,
+        )
+    };
+    let zoom_button = |direction: f32| {
+        (
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(knob_size),
+                    height: Val::Px(knob_size),
+                    ..default()
+                },
+                background_color: FOREGROUND.into(),
+                ..default()
+            },
+            TouchZoomButton(direction),
+        )
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(controls.margin),
+                    right: Val::Px(controls.margin),
+                    bottom: Val::Px(controls.margin),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::End,
+                    ..default()
+                },
+                ..default()
+            },
+            TouchControlsRoot,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(joystick(JoystickKind::Pan))
+                .with_children(|j| {
+                    j.spawn(knob());
+                });
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        column_gap: Val::Px(knob_size * 0.5),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|buttons| {
+                    buttons.spawn(zoom_button(-1.0));
+                    buttons.spawn(zoom_button(1.0));
+                });
+            parent
+                .spawn(joystick(JoystickKind::Orbit))
+                .with_children(|j| {
+                    j.spawn(knob());
+                });
+        });
+}
+
+/// Tracks the touches on each joystick, and sends commands for the joysticks and zoom buttons
+pub(crate) fn touch_controls_input(
+    controls: Res<PanOrbitTouchControls>,
+    touches: Res<Touches>,
+    time: Res<Time<Real>>,
+    mut joysticks: Query<(&mut TouchJoystick, &Node, &GlobalTransform, &Children)>,
+    mut knobs: Query<(&Node, &mut Style), With<TouchJoystickKnob>>,
+    zoom_buttons: Query<(&Interaction, &TouchZoomButton)>,
+    mut command_events: EventWriter<PanOrbitCommand>,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut joystick, node, global_transform, children) in joysticks.iter_mut() {
+        let center = global_transform.translation().truncate();
+        let radius = node.size().x * 0.5;
+        if radius <= 0.0 {
+            continue;
+        }
+
+        if joystick.touch.is_none() {
+            joystick.touch = touches
+                .iter_just_pressed()
+                .find(|touch| touch.position().distance(center) <= radius)
+                .map(|touch| touch.id());
+        }
+        let position = joystick.touch.and_then(|id| touches.get_pressed(id));
+        if position.is_none() {
+            joystick.touch = None;
+        }
+        // Screen coordinates, so positive y is down
+        joystick.deflection = position.map_or(Vec2::ZERO, |touch| {
+            ((touch.position() - center) / radius).clamp_length_max(1.0)
+        });
+
+        for child in children.iter() {
+            if let Ok((knob_node, mut style)) = knobs.get_mut(*child) {
+                let offset = radius - knob_node.size().x * 0.5;
+                let knob_pos = Vec2::splat(offset) + joystick.deflection * offset;
+                style.left = Val::Px(knob_pos.x);
+                style.top = Val::Px(knob_pos.y);
+            }
+        }
+
+        let deflection = joystick.deflection;
+        if deflection == Vec2::ZERO {
+            continue;
+        }
+        command_events.send(match joystick.kind {
+            // Same directions as dragging with the mouse
+            JoystickKind::Orbit => PanOrbitCommand::Orbit(
+                Vec2::new(-deflection.x, deflection.y) * controls.orbit_speed * dt,
+            ),
+            JoystickKind::Pan => PanOrbitCommand::Pan(
+                Vec2::new(deflection.x, -deflection.y) * controls.pan_speed * dt,
+            ),
+        });
+    }
+
+    for (interaction, button) in zoom_buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            command_events.send(PanOrbitCommand::Zoom(button.0 * controls.zoom_speed * dt));
+        }
+    }
+}
@@ -1,11 +1,24 @@
-use crate::PanOrbitCamera;
+use crate::key_chord::MODIFIER_KEYS;
+use crate::{KeyChord, PanOrbitCamera};
 use bevy::input::Input;
-use bevy::math::{Quat, Vec3};
-use bevy::prelude::{KeyCode, MouseButton, Res, Transform};
+use bevy::math::{Quat, Vec2, Vec3};
+use bevy::prelude::{
+    Camera, Entity, GlobalTransform, KeyCode, MouseButton, Mut, Projection, Query, Reflect, Res,
+    Transform, Window, With, Without,
+};
+use bevy::render::camera::RenderTarget;
+use bevy::render::primitives::Aabb;
+use bevy::window::{PrimaryWindow, WindowRef};
 use bevy_easings::Lerp;
+use std::f32::consts::{PI, TAU};
+use std::ops::{Add, Div, Mul, Sub};
 
 const EPSILON: f32 = 0.001;
 
+/// How close to a pole, as the cosine of beta, `arc_angles` starts easing alpha towards the
+/// linear alpha
+const ARC_POLE_BLEND: f32 = 0.05;
+
 pub fn calculate_from_translation_and_focus(translation: Vec3, focus: Vec3) -> (f32, f32, f32) {
     let comp_vec = translation - focus;
     let mut radius = comp_vec.length();
@@ -21,20 +34,89 @@ pub fn calculate_from_translation_and_focus(translation: Vec3, focus: Vec3) -> (
     (alpha, beta, radius)
 }
 
+/// Returns the alpha, beta, and radius that make a perspective camera looking in `direction` fit a
+/// sphere with the given radius in view, with `padding_fraction` extra space around it.
+/// `fov` is the vertical field of view in radians, and `aspect_ratio` is width divided by height.
+pub fn fit_sphere(
+    sphere_radius: f32,
+    direction: Vec3,
+    padding_fraction: f32,
+    fov: f32,
+    aspect_ratio: f32,
+) -> (f32, f32, f32) {
+    // Fit whichever of the horizontal and vertical field of view is narrower
+    let half_fov_y = fov / 2.0;
+    let half_fov_x = (half_fov_y.tan() * aspect_ratio).atan();
+    let half_fov = half_fov_x.min(half_fov_y);
+    let radius = (sphere_radius * (1.0 + padding_fraction) / half_fov.sin()).max(0.05);
+
+    // The camera sits on the opposite side of the focus to the direction it looks in
+    let offset = -direction.normalize();
+    let alpha = offset.x.atan2(offset.z);
+    let beta = offset.y.clamp(-1.0, 1.0).asin();
+    (alpha, beta, radius)
+}
+
+/// The actions that can be bound to a mouse button and modifier keys
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Action {
+    Orbit,
+    Pan,
+    Zoom,
+}
+
+/// All the keys that must be held for the given action's button to work
+fn action_modifiers(pan_orbit: &PanOrbitCamera, action: Action) -> KeyChord {
+    match action {
+        Action::Orbit => pan_orbit
+            .bindings
+            .chord_orbit
+            .with(pan_orbit.bindings.modifier_orbit),
+        Action::Pan => pan_orbit
+            .bindings
+            .chord_pan
+            .with(pan_orbit.bindings.modifier_pan),
+        Action::Zoom => pan_orbit
+            .bindings
+            .chord_zoom
+            .with(pan_orbit.bindings.modifier_zoom),
+    }
+}
+
+/// Whether the keys currently held select the given action. An action is blocked while all the
+/// modifiers of another action are held, unless those are a subset of its own modifiers, so the
+/// most specific binding wins, e.g. Ctrl+Shift+drag pans rather than orbits if orbit is bound to
+/// Ctrl and pan to Ctrl+Shift.
+fn modifiers_select(
+    pan_orbit: &PanOrbitCamera,
+    action: Action,
+    key_input: &Input<KeyCode>,
+) -> bool {
+    let own = action_modifiers(pan_orbit, action);
+    if !own.pressed(key_input) {
+        return false;
+    }
+    let blocked = [Action::Orbit, Action::Pan, Action::Zoom]
+        .into_iter()
+        .filter(|other| *other != action)
+        .map(|other| action_modifiers(pan_orbit, other))
+        .any(|other| !other.is_empty() && other.pressed(key_input) && !other.is_subset_of(&own));
+    if blocked {
+        return false;
+    }
+    !pan_orbit.bindings.exclusive_modifiers
+        || MODIFIER_KEYS
+            .iter()
+            .all(|key| own.contains(*key) || !key_input.pressed(*key))
+}
+
 pub fn orbit_pressed(
     pan_orbit: &PanOrbitCamera,
     mouse_input: &Res<Input<MouseButton>>,
     key_input: &Res<Input<KeyCode>>,
 ) -> bool {
-    let is_pressed = pan_orbit
-        .modifier_orbit
-        .map_or(true, |modifier| key_input.pressed(modifier))
-        && mouse_input.pressed(pan_orbit.button_orbit);
-
-    is_pressed
-        && pan_orbit
-            .modifier_pan
-            .map_or(true, |modifier| !key_input.pressed(modifier))
+    modifiers_select(pan_orbit, Action::Orbit, key_input)
+        && mouse_input.pressed(pan_orbit.bindings.button_orbit)
 }
 
 pub fn orbit_just_pressed(
@@ -42,15 +124,8 @@ pub fn orbit_just_pressed(
     mouse_input: &Res<Input<MouseButton>>,
     key_input: &Res<Input<KeyCode>>,
 ) -> bool {
-    let just_pressed = pan_orbit
-        .modifier_orbit
-        .map_or(true, |modifier| key_input.pressed(modifier))
-        && (mouse_input.just_pressed(pan_orbit.button_orbit));
-
-    just_pressed
-        && pan_orbit
-            .modifier_pan
-            .map_or(true, |modifier| !key_input.pressed(modifier))
+    modifiers_select(pan_orbit, Action::Orbit, key_input)
+        && mouse_input.just_pressed(pan_orbit.bindings.button_orbit)
 }
 
 pub fn orbit_just_released(
@@ -58,15 +133,8 @@ pub fn orbit_just_released(
     mouse_input: &Res<Input<MouseButton>>,
     key_input: &Res<Input<KeyCode>>,
 ) -> bool {
-    let just_released = pan_orbit
-        .modifier_orbit
-        .map_or(true, |modifier| key_input.pressed(modifier))
-        && (mouse_input.just_released(pan_orbit.button_orbit));
-
-    just_released
-        && pan_orbit
-            .modifier_pan
-            .map_or(true, |modifier| !key_input.pressed(modifier))
+    modifiers_select(pan_orbit, Action::Orbit, key_input)
+        && mouse_input.just_released(pan_orbit.bindings.button_orbit)
 }
 
 pub fn pan_pressed(
@@ -74,15 +142,8 @@ pub fn pan_pressed(
     mouse_input: &Res<Input<MouseButton>>,
     key_input: &Res<Input<KeyCode>>,
 ) -> bool {
-    let is_pressed = pan_orbit
-        .modifier_pan
-        .map_or(true, |modifier| key_input.pressed(modifier))
-        && mouse_input.pressed(pan_orbit.button_pan);
-
-    is_pressed
-        && pan_orbit
-            .modifier_orbit
-            .map_or(true, |modifier| !key_input.pressed(modifier))
+    modifiers_select(pan_orbit, Action::Pan, key_input)
+        && mouse_input.pressed(pan_orbit.bindings.button_pan)
 }
 
 pub fn pan_just_pressed(
@@ -90,32 +151,309 @@ pub fn pan_just_pressed(
     mouse_input: &Res<Input<MouseButton>>,
     key_input: &Res<Input<KeyCode>>,
 ) -> bool {
-    let just_pressed = pan_orbit
-        .modifier_pan
-        .map_or(true, |modifier| key_input.pressed(modifier))
-        && (mouse_input.just_pressed(pan_orbit.button_pan));
+    modifiers_select(pan_orbit, Action::Pan, key_input)
+        && mouse_input.just_pressed(pan_orbit.bindings.button_pan)
+}
 
-    just_pressed
-        && pan_orbit
-            .modifier_orbit
-            .map_or(true, |modifier| !key_input.pressed(modifier))
+pub fn zoom_pressed(
+    pan_orbit: &PanOrbitCamera,
+    mouse_input: &Res<Input<MouseButton>>,
+    key_input: &Res<Input<KeyCode>>,
+) -> bool {
+    pan_orbit.bindings.button_zoom.is_some_and(|button| {
+        modifiers_select(pan_orbit, Action::Zoom, key_input) && mouse_input.pressed(button)
+    })
 }
 
-/// Update `transform` based on alpha, beta, and the camera's focus and radius
+pub fn zoom_just_pressed(
+    pan_orbit: &PanOrbitCamera,
+    mouse_input: &Res<Input<MouseButton>>,
+    key_input: &Res<Input<KeyCode>>,
+) -> bool {
+    pan_orbit.bindings.button_zoom.is_some_and(|button| {
+        modifiers_select(pan_orbit, Action::Zoom, key_input) && mouse_input.just_pressed(button)
+    })
+}
+
+pub fn roll_pressed(pan_orbit: &PanOrbitCamera, mouse_input: &Res<Input<MouseButton>>) -> bool {
+    pan_orbit
+        .bindings
+        .button_roll
+        .is_some_and(|button| mouse_input.pressed(button))
+}
+
+pub fn roll_just_pressed(
+    pan_orbit: &PanOrbitCamera,
+    mouse_input: &Res<Input<MouseButton>>,
+) -> bool {
+    pan_orbit
+        .bindings
+        .button_roll
+        .is_some_and(|button| mouse_input.just_pressed(button))
+}
+
+/// Whether any of the mouse buttons that drag the camera are held, regardless of modifiers
+pub fn drag_button_pressed(pan_orbit: &PanOrbitCamera, mouse_input: &Input<MouseButton>) -> bool {
+    [
+        Some(pan_orbit.bindings.button_orbit),
+        Some(pan_orbit.bindings.button_pan),
+        pan_orbit.bindings.button_zoom,
+        pan_orbit.bindings.button_roll,
+    ]
+    .into_iter()
+    .flatten()
+    .any(|button| mouse_input.pressed(button))
+}
+
+/// Update `transform` based on alpha, beta, roll, and the camera's focus and radius. `offset` is
+/// in the camera's local space, and shifts the camera without changing what it orbits around.
+/// Alpha and beta are relative to `base_rotation`.
 pub fn update_orbit_transform(
     alpha: f32,
     beta: f32,
+    roll: f32,
     radius: f32,
     focus: Vec3,
+    offset: Vec3,
+    base_rotation: Quat,
     transform: &mut Transform,
 ) {
-    let mut rotation = Quat::from_rotation_y(alpha);
+    let mut rotation = base_rotation * Quat::from_rotation_y(alpha);
     rotation *= Quat::from_rotation_x(-beta);
+    // Roll around the view axis. This doesn't move the camera, since it lies on that axis.
+    rotation *= Quat::from_rotation_z(roll);
     transform.rotation = rotation;
 
     // Update the translation of the camera so we are always rotating 'around'
     // (orbiting) rather than rotating in place
-    transform.translation = focus + transform.rotation * Vec3::new(0.0, 0.0, radius);
+    transform.translation = focus + transform.rotation * (Vec3::new(0.0, 0.0, radius) + offset);
+}
+
+/// Returns whether the translations of two transforms are further apart than `epsilon`, or their
+/// rotations differ by more than `epsilon` radians. With an `epsilon` of `0.0`, any difference
+/// counts. Scale is ignored.
+pub fn transform_differs(a: &Transform, b: &Transform, epsilon: f32) -> bool {
+    if epsilon == 0.0 {
+        return a.translation != b.translation || a.rotation != b.rotation;
+    }
+    // `Quat::angle_between` goes through `acos`, which rounds small angles to zero, so work out
+    // the angle from the distance between the quaternions instead
+    let chord = (a.rotation - b.rotation)
+        .length()
+        .min((a.rotation + b.rotation).length());
+    let angle = 4.0 * (chord / 2.0).min(1.0).asin();
+    a.translation.distance(b.translation) > epsilon || angle > epsilon
+}
+
+/// Returns the direction a camera with the given alpha and beta looks in, i.e. from the camera
+/// towards the focus.
+pub fn orbit_forward(alpha: f32, beta: f32, base_rotation: Quat) -> Vec3 {
+    base_rotation * Quat::from_rotation_y(alpha) * Quat::from_rotation_x(-beta) * Vec3::NEG_Z
+}
+
+/// Sets the scale of an orthographic projection. The projection is only borrowed mutably if the
+/// scale actually changes, so perspective and unchanged projections don't trigger change
+/// detection.
+pub fn set_orthographic_scale(projection: &mut Mut<Projection>, scale: f32) {
+    if let Projection::Orthographic(ref p) = **projection {
+        if p.scale != scale {
+            if let Projection::Orthographic(ref mut p) = **projection {
+                p.scale = scale;
+            }
+        }
+    }
+}
+
+/// Returns the cursor position relative to the top left of the camera's viewport, if the cursor
+/// is inside the window that the camera renders to
+pub fn cursor_viewport_position(
+    camera: &Camera,
+    primary_windows: &Query<&Window, With<PrimaryWindow>>,
+    other_windows: &Query<&Window, Without<PrimaryWindow>>,
+) -> Option<Vec2> {
+    let RenderTarget::Window(win_ref) = camera.target else {
+        return None;
+    };
+    let window = match win_ref {
+        WindowRef::Primary => primary_windows.get_single().ok()?,
+        WindowRef::Entity(entity) => other_windows.get(entity).ok()?,
+    };
+    let cursor_pos = window.cursor_position()?;
+    let viewport_min = camera
+        .logical_viewport_rect()
+        .map_or(Vec2::ZERO, |rect| rect.min);
+    Some(cursor_pos - viewport_min)
+}
+
+/// Returns the alpha and beta a fraction `t` of the way along the shortest arc between two
+/// orbit directions, at a constant angular speed. The alpha is unwrapped so it stays close to
+/// the linearly interpolated alpha, and both ends match the given values exactly. Beta must be
+/// within ±90 degrees. Opposite directions have no single shortest arc, so alpha and beta are
+/// interpolated linearly instead. Close to the poles, where the azimuth swings around quickly,
+/// alpha eases towards the linear alpha, so it stays continuous even when the arc passes straight
+/// over a pole.
+pub fn arc_angles(
+    from_alpha: f32,
+    from_beta: f32,
+    to_alpha: f32,
+    to_beta: f32,
+    t: f32,
+) -> (f32, f32) {
+    if t <= 0.0 {
+        return (from_alpha, from_beta);
+    }
+    if t >= 1.0 {
+        return (to_alpha, to_beta);
+    }
+    // The direction from the focus to the camera
+    let direction = |alpha: f32, beta: f32| {
+        Vec3::new(
+            alpha.sin() * beta.cos(),
+            beta.sin(),
+            alpha.cos() * beta.cos(),
+        )
+    };
+    let from = direction(from_alpha, from_beta);
+    let to = direction(to_alpha, to_beta);
+    let linear_alpha = from_alpha + (to_alpha - from_alpha) * t;
+    let linear_beta = from_beta + (to_beta - from_beta) * t;
+    let angle = from.dot(to).clamp(-1.0, 1.0).acos();
+    // The arc is a straight line when the directions are the same, and undefined when they are
+    // opposite, so interpolate linearly in those cases
+    if !(EPSILON..=PI - EPSILON).contains(&angle) {
+        return (linear_alpha, linear_beta);
+    }
+    let current = (from * ((1.0 - t) * angle).sin() + to * (t * angle).sin()) / angle.sin();
+    // The azimuth jumps by half a turn when passing straight over a pole, and is undefined right
+    // at it, so blend to the linear alpha as the horizontal part of the direction vanishes
+    let horizontal = Vec2::new(current.x, current.z).length();
+    let alpha = match horizontal < f32::EPSILON {
+        true => linear_alpha,
+        false => {
+            let delta = shortest_angle_delta(linear_alpha, current.x.atan2(current.z));
+            linear_alpha + delta * (horizontal / ARC_POLE_BLEND).min(1.0)
+        }
+    };
+    (alpha, current.y.clamp(-1.0, 1.0).asin())
+}
+
+/// Returns whether the camera is upside down at the given beta, given whether it was upside down
+/// before. Beta has to go `hysteresis` radians past straight up or straight down for the result
+/// to change, so it doesn't flip back and forth when beta stays close to a pole.
+pub fn update_upside_down(was_upside_down: bool, beta: f32, hysteresis: f32) -> bool {
+    // Distance from the horizon, from 0 (upright) to PI (upside down)
+    let wrapped_beta = (beta + PI).rem_euclid(TAU) - PI;
+    let from_horizon = wrapped_beta.abs();
+    match was_upside_down {
+        true => from_horizon > PI / 2.0 - hysteresis,
+        false => from_horizon > PI / 2.0 + hysteresis,
+    }
+}
+
+/// Returns the logical size of the camera's viewport in the given window, using the window's
+/// current scale factor rather than the one cached in the camera, which isn't updated until
+/// `PostUpdate` after the window is resized or moved to a monitor with a different scale factor.
+pub fn logical_viewport_size(camera: &Camera, window: &Window) -> Vec2 {
+    match &camera.viewport {
+        Some(viewport) => viewport.physical_size.as_vec2() / window.scale_factor() as f32,
+        None => Vec2::new(window.width(), window.height()),
+    }
+}
+
+/// Returns the direction to pan in when the cursor is within `margin` of the edges of a
+/// viewport of the given size, in screen space, i.e. positive Y is down. Each axis is `-1.0`,
+/// `0.0`, or `1.0`. Returns zero if the cursor is outside the viewport.
+pub fn edge_pan_direction(cursor_pos: Vec2, viewport_size: Vec2, margin: f32) -> Vec2 {
+    if cursor_pos.cmplt(Vec2::ZERO).any() || cursor_pos.cmpgt(viewport_size).any() {
+        return Vec2::ZERO;
+    }
+    let axis = |pos: f32, size: f32| {
+        if pos <= margin {
+            -1.0
+        } else if pos >= size - margin {
+            1.0
+        } else {
+            0.0
+        }
+    };
+    Vec2::new(
+        axis(cursor_pos.x, viewport_size.x),
+        axis(cursor_pos.y, viewport_size.y),
+    )
+}
+
+/// Returns the distance along the ray at which it enters the box defined by `min` and `max`,
+/// or `None` if it misses. If the ray starts inside the box, the distance is `0.0`.
+pub fn ray_aabb_intersection(origin: Vec3, direction: Vec3, min: Vec3, max: Vec3) -> Option<f32> {
+    let inv_direction = direction.recip();
+    let t1 = (min - origin) * inv_direction;
+    let t2 = (max - origin) * inv_direction;
+    let t_enter = t1.min(t2).max_element();
+    let t_exit = t1.max(t2).min_element();
+    if t_exit < 0.0 || t_enter > t_exit {
+        return None;
+    }
+    Some(t_enter.max(0.0))
+}
+
+/// Returns the point where the ray intersects the plane going through `plane_origin`, or `None`
+/// if the ray is parallel to the plane or points away from it
+pub fn ray_plane_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    plane_origin: Vec3,
+    plane_normal: Vec3,
+) -> Option<Vec3> {
+    let denominator = direction.dot(plane_normal);
+    if denominator.abs() < f32::EPSILON {
+        return None;
+    }
+    let distance = (plane_origin - origin).dot(plane_normal) / denominator;
+    (distance >= 0.0).then(|| origin + direction * distance)
+}
+
+/// Casts a ray against the bounding boxes of entities and returns the closest hit point
+pub fn raycast_aabbs<'a>(
+    origin: Vec3,
+    direction: Vec3,
+    aabbs: impl Iterator<Item = (&'a Aabb, &'a GlobalTransform)>,
+) -> Option<Vec3> {
+    aabbs
+        .filter_map(|(aabb, global_transform)| {
+            // Intersect in the entity's local space so that rotation and scale are respected.
+            // The ray parameter is unchanged by affine transforms, so the distance is still valid
+            // in world space.
+            let world_to_local = global_transform.affine().inverse();
+            ray_aabb_intersection(
+                world_to_local.transform_point3(origin),
+                world_to_local.transform_vector3(direction),
+                (aabb.center - aabb.half_extents).into(),
+                (aabb.center + aabb.half_extents).into(),
+            )
+        })
+        .min_by(|a, b| a.total_cmp(b))
+        .map(|distance| origin + direction * distance)
+}
+
+/// Returns the minimum and maximum corners of the world space box that contains all of the given
+/// `Aabb`s, or `None` if there are none.
+pub fn combined_bounds<'a>(
+    aabbs: impl Iterator<Item = (&'a Aabb, &'a GlobalTransform)>,
+) -> Option<(Vec3, Vec3)> {
+    aabbs
+        .map(|(aabb, global_transform)| {
+            // The world space extents of a rotated box are the absolute projections of its
+            // local axes
+            let affine = global_transform.affine();
+            let center = affine.transform_point3(aabb.center.into());
+            let matrix = affine.matrix3;
+            let half_extents = Vec3::from(aabb.half_extents);
+            let extents = matrix.x_axis.abs() * half_extents.x
+                + matrix.y_axis.abs() * half_extents.y
+                + matrix.z_axis.abs() * half_extents.z;
+            (center - Vec3::from(extents), center + Vec3::from(extents))
+        })
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
 }
 
 pub fn apply_limits(value: f32, upper_limit: Option<f32>, lower_limit: Option<f32>) -> f32 {
@@ -129,24 +467,198 @@ pub fn apply_limits(value: f32, upper_limit: Option<f32>, lower_limit: Option<f3
     new_val
 }
 
-pub fn approx_equal(a: f32, b: f32) -> bool {
-    (a - b).abs() < EPSILON
+/// Returns the smallest signed angle (in radians) that rotates `from` to `to`, in the range
+/// `[-PI, PI]`
+pub fn shortest_angle_delta(from: f32, to: f32) -> f32 {
+    let delta = (to - from).rem_euclid(TAU);
+    if delta > PI {
+        delta - TAU
+    } else {
+        delta
+    }
+}
+
+/// Returns the lowest beta that keeps a camera at `radius` from a focus at height `focus_y` at or
+/// above `ground_y`.
+pub fn min_beta_above_ground(ground_y: f32, focus_y: f32, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return -PI / 2.0;
+    }
+    ((ground_y - focus_y) / radius).clamp(-1.0, 1.0).asin()
+}
+
+/// Rounds `value` to the nearest multiple of `increment`. Returns `value` unchanged if `increment`
+/// is not positive.
+pub fn snap_to_increment(value: f32, increment: f32) -> f32 {
+    if increment <= 0.0 {
+        return value;
+    }
+    (value / increment).round() * increment
+}
+
+/// Moves `from` towards `to` by at most `max_step`, without overshooting
+pub fn move_towards(from: f32, to: f32, max_step: f32) -> f32 {
+    if (to - from).abs() <= max_step {
+        to
+    } else {
+        from + (to - from).signum() * max_step
+    }
+}
+
+/// Moves `from` towards `to` by a distance of at most `max_step`, without overshooting
+pub fn move_towards_vec3(from: Vec3, to: Vec3, max_step: f32) -> Vec3 {
+    from + (to - from).clamp_length_max(max_step.max(0.0))
+}
+
+/// Returns the entity after (or before, if `reverse` is `true`) `current` in `entities`, wrapping
+/// around at the ends. If `current` is `None` or not in the list, returns the first (or last)
+/// entity.
+pub fn cycle_entity(entities: &[Entity], current: Option<Entity>, reverse: bool) -> Option<Entity> {
+    let len = entities.len();
+    if len == 0 {
+        return None;
+    }
+    let index = match current.and_then(|current| entities.iter().position(|e| *e == current)) {
+        Some(index) if reverse => (index + len - 1) % len,
+        Some(index) => (index + 1) % len,
+        None if reverse => len - 1,
+        None => 0,
+    };
+    Some(entities[index])
+}
+
+/// Converts a smoothness value, which is defined per frame at 60 FPS, to the equivalent value for
+/// a frame that took `delta_seconds`. A smoothness of `0.0` always stays `0.0`, so that disabling
+/// smoothing gives a 1:1 mapping of input to movement even when time is paused.
+pub fn frame_smoothness(smoothness: f32, delta_seconds: f32) -> f32 {
+    if smoothness <= 0.0 {
+        return 0.0;
+    }
+    smoothness.powf(delta_seconds * 60.0)
+}
+
+/// A value that can be animated by `spring_step`
+pub trait SpringValue:
+    Copy
+    + PartialEq
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f32, Output = Self>
+    + Div<f32, Output = Self>
+{
+    /// The magnitude of the value, used to decide when the spring has come to rest
+    fn magnitude(self) -> f32;
+}
+
+impl SpringValue for f32 {
+    fn magnitude(self) -> f32 {
+        self.abs()
+    }
+}
+
+impl SpringValue for Vec3 {
+    fn magnitude(self) -> f32 {
+        self.length()
+    }
+}
+
+/// The state of a value that is animated by `spring_step`
+#[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
+pub struct Spring<T> {
+    /// The current rate of change of the value, per second
+    pub velocity: T,
+    /// The target from the previous step, used to estimate how fast the target is moving
+    pub previous_target: Option<T>,
+}
+
+/// Moves `value` towards `target` using second-order dynamics, i.e. a damped spring, and returns
+/// the new value. `frequency` is the natural frequency in Hz, `damping` is the damping ratio
+/// (`1.0` is critically damped, lower values overshoot), and `response` controls the initial
+/// reaction to a change in target (`0.0` starts slowly, `1.0` reacts immediately, higher values
+/// overshoot, and negative values anticipate). Snaps to the target once both the distance to it
+/// and the velocity are below `snap_threshold`, so the motion eventually stops.
+pub fn spring_step<T: SpringValue>(
+    value: T,
+    target: T,
+    spring: &mut Spring<T>,
+    frequency: f32,
+    damping: f32,
+    response: f32,
+    snap_threshold: f32,
+    delta_seconds: f32,
+) -> T {
+    if delta_seconds <= 0.0 || frequency <= 0.0 {
+        return value;
+    }
+    let target_velocity = spring
+        .previous_target
+        .map_or(T::default(), |previous| (target - previous) / delta_seconds);
+    spring.previous_target = Some(target);
+
+    let k1 = damping / (PI * frequency);
+    let k2 = 1.0 / (TAU * frequency).powi(2);
+    let k3 = response * damping / (TAU * frequency);
+    // Clamp k2 to keep the integration stable for large time steps
+    let k2 = k2.max(1.1 * (delta_seconds * delta_seconds / 4.0 + delta_seconds * k1 / 2.0));
+
+    let new_value = value + spring.velocity * delta_seconds;
+    spring.velocity = spring.velocity
+        + (target + target_velocity * k3 - new_value - spring.velocity * k1) * (delta_seconds / k2);
+
+    if (new_value - target).magnitude() < snap_threshold
+        && spring.velocity.magnitude() < snap_threshold
+    {
+        spring.velocity = T::default();
+        return target;
+    }
+    new_value
+}
+
+/// How far a point at distance `radius` in front of the camera has to move to follow the cursor
+/// when it moves `delta_px` logical pixels, using the same scaling as panning. `delta_px` is in
+/// window coordinates, i.e. with Y pointing down, and the result is in the camera's local space,
+/// i.e. X is right and Y is up, so rotate it by the camera's rotation to get the world space
+/// delta. `radius` is ignored for orthographic projections.
+/// This is useful for dragging objects in the scene, so they move exactly as far as the scene
+/// does when panning.
+pub fn pixels_to_world_pan(
+    delta_px: Vec2,
+    projection: &Projection,
+    viewport_size: Vec2,
+    radius: f32,
+) -> Vec3 {
+    let scaled = match *projection {
+        Projection::Perspective(ref p) => {
+            delta_px * Vec2::new(p.fov * p.aspect_ratio, p.fov) / viewport_size * radius
+        }
+        Projection::Orthographic(ref p) => {
+            delta_px * Vec2::new(p.area.width(), p.area.height()) / viewport_size
+        }
+    };
+    Vec3::new(scaled.x, -scaled.y, 0.0)
 }
 
-pub fn lerp_and_snap_f32(from: f32, to: f32, smoothness: f32) -> f32 {
+/// Moves `from` towards `to`, where `smoothness` is the fraction of the distance that remains
+/// after this step (so `0.0` jumps straight to `to`, and `1.0` doesn't move at all). Once the
+/// result is within `snap_threshold` of `to`, it snaps to `to` exactly, so the motion eventually
+/// stops.
+pub fn lerp_and_snap_f32(from: f32, to: f32, smoothness: f32, snap_threshold: f32) -> f32 {
     let t = 1.0 - smoothness;
     let mut new_value = from.lerp(&to, &t);
-    if smoothness < 1.0 && approx_equal(new_value, to) {
+    if smoothness < 1.0 && (new_value - to).abs() < snap_threshold {
         new_value = to;
     }
     new_value
 }
 
-pub fn lerp_and_snap_vec3(from: Vec3, to: Vec3, smoothness: f32) -> Vec3 {
+/// Same as `lerp_and_snap_f32`, but for vectors. The result snaps once its distance to `to` is
+/// below `snap_threshold`.
+pub fn lerp_and_snap_vec3(from: Vec3, to: Vec3, smoothness: f32, snap_threshold: f32) -> Vec3 {
     let t = 1.0 - smoothness;
     let mut new_value = from.lerp(to, t);
-    if smoothness < 1.0 && approx_equal((new_value - to).length(), 0.0) {
-        new_value.x = to.x;
+    if smoothness < 1.0 && (new_value - to).length() < snap_threshold {
+        new_value = to;
     }
     new_value
 }
@@ -208,6 +720,115 @@ mod calculate_from_translation_and_focus_tests {
     }
 }
 
+#[cfg(test)]
+mod update_orbit_transform_tests {
+    use super::*;
+
+    #[test]
+    fn roll_does_not_move_camera() {
+        let mut rolled = Transform::default();
+        let mut unrolled = Transform::default();
+        update_orbit_transform(
+            0.5,
+            0.3,
+            1.0,
+            4.0,
+            Vec3::ONE,
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            &mut rolled,
+        );
+        update_orbit_transform(
+            0.5,
+            0.3,
+            0.0,
+            4.0,
+            Vec3::ONE,
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            &mut unrolled,
+        );
+        assert!(rolled.translation.abs_diff_eq(unrolled.translation, 1e-5));
+        assert!(rolled.forward().abs_diff_eq(unrolled.forward(), 1e-5));
+        assert!(!rolled.up().abs_diff_eq(unrolled.up(), 1e-5));
+    }
+
+    #[test]
+    fn offset_is_in_local_space() {
+        let mut transform = Transform::default();
+        update_orbit_transform(
+            PI / 2.0,
+            0.0,
+            0.0,
+            4.0,
+            Vec3::ZERO,
+            Vec3::X,
+            Quat::IDENTITY,
+            &mut transform,
+        );
+        // Looking along -X, so local right is -Z
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(4.0, 0.0, -1.0), 1e-5));
+    }
+
+    #[test]
+    fn base_rotation_changes_zero_orientation() {
+        let mut transform = Transform::default();
+        // Z-up, so with alpha and beta of zero the camera is on the -Y side looking along +Y
+        let z_up = Quat::from_rotation_x(PI / 2.0);
+        update_orbit_transform(
+            0.0,
+            0.0,
+            0.0,
+            4.0,
+            Vec3::ZERO,
+            Vec3::ZERO,
+            z_up,
+            &mut transform,
+        );
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.0, -4.0, 0.0), 1e-5));
+        assert!(transform.up().abs_diff_eq(Vec3::Z, 1e-5));
+    }
+}
+
+#[cfg(test)]
+mod fit_sphere_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn looking_along_neg_z() {
+        let (alpha, beta, radius) = fit_sphere(1.0, Vec3::NEG_Z, 0.0, PI / 2.0, 1.0);
+        assert!(approx_eq!(f32, alpha, 0.0));
+        assert!(approx_eq!(f32, beta, 0.0));
+        assert!(approx_eq!(f32, radius, 2.0_f32.sqrt()));
+    }
+
+    #[test]
+    fn looking_down() {
+        let (_, beta, _) = fit_sphere(1.0, Vec3::NEG_Y, 0.0, PI / 2.0, 1.0);
+        assert!(approx_eq!(f32, beta, PI / 2.0));
+    }
+
+    #[test]
+    fn looking_along_pos_x() {
+        let (alpha, _, _) = fit_sphere(1.0, Vec3::X, 0.0, PI / 2.0, 1.0);
+        assert!(approx_eq!(f32, alpha, -PI / 2.0));
+    }
+
+    #[test]
+    fn padding_and_narrow_aspect_ratio_increase_radius() {
+        let (_, _, radius) = fit_sphere(1.0, Vec3::NEG_Z, 0.0, PI / 2.0, 1.0);
+        let (_, _, padded) = fit_sphere(1.0, Vec3::NEG_Z, 0.5, PI / 2.0, 1.0);
+        let (_, _, narrow) = fit_sphere(1.0, Vec3::NEG_Z, 0.0, PI / 2.0, 0.5);
+        assert!(approx_eq!(f32, padded, radius * 1.5));
+        assert!(narrow > radius);
+    }
+}
+
 #[cfg(test)]
 mod apply_limits_tests {
     use super::*;
@@ -238,22 +859,255 @@ mod apply_limits_tests {
 }
 
 #[cfg(test)]
-mod approx_equal_tests {
+mod ray_aabb_intersection_tests {
+    use super::*;
+
+    #[test]
+    fn hits_box_in_front() {
+        let distance = ray_aabb_intersection(Vec3::Z * 5.0, Vec3::NEG_Z, -Vec3::ONE, Vec3::ONE);
+        assert_eq!(distance, Some(4.0));
+    }
+
+    #[test]
+    fn misses_box_behind() {
+        let distance = ray_aabb_intersection(Vec3::Z * 5.0, Vec3::Z, -Vec3::ONE, Vec3::ONE);
+        assert_eq!(distance, None);
+    }
+
+    #[test]
+    fn misses_box_to_the_side() {
+        let origin = Vec3::new(3.0, 0.0, 5.0);
+        let distance = ray_aabb_intersection(origin, Vec3::NEG_Z, -Vec3::ONE, Vec3::ONE);
+        assert_eq!(distance, None);
+    }
+
+    #[test]
+    fn starting_inside_box_is_zero() {
+        let distance = ray_aabb_intersection(Vec3::ZERO, Vec3::NEG_Z, -Vec3::ONE, Vec3::ONE);
+        assert_eq!(distance, Some(0.0));
+    }
+}
+
+#[cfg(test)]
+mod ray_plane_intersection_tests {
+    use super::*;
+
+    #[test]
+    fn hits_plane_in_front() {
+        let hit =
+            ray_plane_intersection(Vec3::new(1.0, 2.0, 5.0), Vec3::NEG_Z, Vec3::ZERO, Vec3::Z);
+        assert_eq!(hit, Some(Vec3::new(1.0, 2.0, 0.0)));
+    }
+
+    #[test]
+    fn misses_plane_behind() {
+        let hit = ray_plane_intersection(Vec3::Z * 5.0, Vec3::Z, Vec3::ZERO, Vec3::Z);
+        assert_eq!(hit, None);
+    }
+
+    #[test]
+    fn parallel_ray_misses() {
+        let hit = ray_plane_intersection(Vec3::Z * 5.0, Vec3::X, Vec3::ZERO, Vec3::Z);
+        assert_eq!(hit, None);
+    }
+}
+
+#[cfg(test)]
+mod shortest_angle_delta_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn small_positive_delta() {
+        assert!(approx_eq!(f32, shortest_angle_delta(0.0, 1.0), 1.0));
+    }
+
+    #[test]
+    fn wraps_to_negative_delta() {
+        let delta = shortest_angle_delta(0.0, 350f32.to_radians());
+        assert!(approx_eq!(
+            f32,
+            delta,
+            -10f32.to_radians(),
+            epsilon = 0.0001
+        ));
+    }
+
+    #[test]
+    fn ignores_full_turns() {
+        let delta = shortest_angle_delta(TAU * 3.0, TAU + 0.5);
+        assert!(approx_eq!(f32, delta, 0.5, epsilon = 0.0001));
+    }
+}
+
+#[cfg(test)]
+mod snap_to_increment_tests {
     use super::*;
+    use float_cmp::approx_eq;
 
     #[test]
-    fn same_value_is_approx_equal() {
-        assert!(approx_equal(1.0, 1.0));
+    fn rounds_to_nearest_multiple() {
+        assert!(approx_eq!(f32, snap_to_increment(0.3, 0.25), 0.25));
+        assert!(approx_eq!(f32, snap_to_increment(0.4, 0.25), 0.5));
+        assert!(approx_eq!(f32, snap_to_increment(-0.4, 0.25), -0.5));
     }
 
     #[test]
-    fn value_within_threshold_is_approx_equal() {
-        assert!(approx_equal(1.0, 1.0000001));
+    fn ignores_non_positive_increment() {
+        assert_eq!(snap_to_increment(0.3, 0.0), 0.3);
+        assert_eq!(snap_to_increment(0.3, -1.0), 0.3);
+    }
+}
+
+#[cfg(test)]
+mod move_towards_tests {
+    use super::*;
+
+    #[test]
+    fn moves_by_max_step() {
+        assert_eq!(move_towards(0.0, 1.0, 0.25), 0.25);
+        assert_eq!(move_towards(1.0, 0.0, 0.25), 0.75);
     }
 
     #[test]
-    fn value_outside_threshold_is_not_approx_equal() {
-        assert!(!approx_equal(1.0, 1.01));
+    fn does_not_overshoot() {
+        assert_eq!(move_towards(0.9, 1.0, 0.25), 1.0);
+    }
+}
+
+#[cfg(test)]
+mod cycle_entity_tests {
+    use super::*;
+
+    fn entities() -> Vec<Entity> {
+        (0..3).map(Entity::from_raw).collect()
+    }
+
+    #[test]
+    fn empty_list_returns_none() {
+        assert_eq!(cycle_entity(&[], Some(Entity::from_raw(0)), false), None);
+    }
+
+    #[test]
+    fn starts_at_either_end_without_current() {
+        let entities = entities();
+        assert_eq!(cycle_entity(&entities, None, false), Some(entities[0]));
+        assert_eq!(cycle_entity(&entities, None, true), Some(entities[2]));
+    }
+
+    #[test]
+    fn wraps_around() {
+        let entities = entities();
+        assert_eq!(
+            cycle_entity(&entities, Some(entities[2]), false),
+            Some(entities[0])
+        );
+        assert_eq!(
+            cycle_entity(&entities, Some(entities[0]), true),
+            Some(entities[2])
+        );
+    }
+}
+
+#[cfg(test)]
+mod frame_smoothness_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn unchanged_at_60_fps() {
+        assert!(approx_eq!(
+            f32,
+            frame_smoothness(0.8, 1.0 / 60.0),
+            0.8,
+            ulps = 4
+        ));
+    }
+
+    #[test]
+    fn smooths_less_for_longer_frames() {
+        assert!(approx_eq!(
+            f32,
+            frame_smoothness(0.8, 2.0 / 60.0),
+            0.64,
+            ulps = 4
+        ));
+    }
+
+    #[test]
+    fn zero_stays_zero_when_paused() {
+        assert_eq!(frame_smoothness(0.0, 0.0), 0.0);
+        assert_eq!(frame_smoothness(0.8, 0.0), 1.0);
+    }
+}
+
+#[cfg(test)]
+mod spring_step_tests {
+    use super::*;
+
+    fn settle(damping: f32) -> (f32, f32) {
+        let mut spring = Spring::default();
+        let mut value = 0.0;
+        let mut max_value: f32 = 0.0;
+        for _ in 0..600 {
+            value = spring_step(
+                value,
+                1.0,
+                &mut spring,
+                2.0,
+                damping,
+                0.0,
+                EPSILON,
+                1.0 / 60.0,
+            );
+            max_value = max_value.max(value);
+        }
+        (value, max_value)
+    }
+
+    #[test]
+    fn settles_on_target() {
+        let (value, _) = settle(1.0);
+        assert_eq!(value, 1.0);
+    }
+
+    #[test]
+    fn critically_damped_does_not_overshoot() {
+        let (_, max_value) = settle(1.0);
+        assert!(max_value <= 1.0);
+    }
+
+    #[test]
+    fn underdamped_overshoots() {
+        let (value, max_value) = settle(0.3);
+        assert_eq!(value, 1.0);
+        assert!(max_value > 1.0);
+    }
+
+    #[test]
+    fn snaps_within_threshold() {
+        let mut spring = Spring::default();
+        let mut value = 0.0;
+        let mut steps = 0;
+        while value != 1.0 {
+            value = spring_step(value, 1.0, &mut spring, 2.0, 1.0, 0.0, 0.1, 1.0 / 60.0);
+            steps += 1;
+        }
+        let mut spring = Spring::default();
+        let mut value = 0.0;
+        for _ in 0..steps {
+            value = spring_step(value, 1.0, &mut spring, 2.0, 1.0, 0.0, EPSILON, 1.0 / 60.0);
+        }
+        assert_ne!(value, 1.0);
+    }
+
+    #[test]
+    fn zero_delta_does_nothing() {
+        let mut spring = Spring::default();
+        assert_eq!(
+            spring_step(0.0, 1.0, &mut spring, 2.0, 1.0, 0.0, EPSILON, 0.0),
+            0.0
+        );
     }
 }
 
@@ -263,26 +1117,34 @@ mod lerp_and_snap_f32_tests {
 
     #[test]
     fn lerps_when_output_outside_snap_threshold() {
-        let out = lerp_and_snap_f32(1.0, 2.0, 0.5);
+        let out = lerp_and_snap_f32(1.0, 2.0, 0.5, EPSILON);
         assert_eq!(out, 1.5);
     }
 
     #[test]
     fn snaps_to_target_when_inside_threshold() {
-        let out = lerp_and_snap_f32(1.9991, 2.0, 0.5);
+        let out = lerp_and_snap_f32(1.9991, 2.0, 0.5, EPSILON);
         assert_eq!(out, 2.0);
-        let out = lerp_and_snap_f32(1.9991, 2.0, 0.1);
+        let out = lerp_and_snap_f32(1.9991, 2.0, 0.1, EPSILON);
         assert_eq!(out, 2.0);
-        let out = lerp_and_snap_f32(1.9991, 2.0, 0.9);
+        let out = lerp_and_snap_f32(1.9991, 2.0, 0.9, EPSILON);
         assert_eq!(out, 2.0);
     }
 
     #[test]
     fn does_not_snap_if_smoothness_is_one() {
         // Smoothness of one results in the value not changing, so it doesn't make sense to snap
-        let out = lerp_and_snap_f32(1.9991, 2.0, 1.0);
+        let out = lerp_and_snap_f32(1.9991, 2.0, 1.0, EPSILON);
         assert_eq!(out, 1.9991);
     }
+
+    #[test]
+    fn uses_given_snap_threshold() {
+        let out = lerp_and_snap_f32(1.0, 2.0, 0.5, 1.0);
+        assert_eq!(out, 2.0);
+        let out = lerp_and_snap_f32(1.9991, 2.0, 0.5, 0.0001);
+        assert!(out < 2.0);
+    }
 }
 
 #[cfg(test)]
@@ -291,24 +1153,389 @@ mod lerp_and_snap_vec3_tests {
 
     #[test]
     fn lerps_when_output_outside_snap_threshold() {
-        let out = lerp_and_snap_vec3(Vec3::ZERO, Vec3::X, 0.5);
+        let out = lerp_and_snap_vec3(Vec3::ZERO, Vec3::X, 0.5, EPSILON);
         assert_eq!(out, Vec3::X * 0.5);
     }
 
     #[test]
     fn snaps_to_target_when_inside_threshold() {
-        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 0.5);
+        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 0.5, EPSILON);
         assert_eq!(out, Vec3::X);
-        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 0.1);
+        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 0.1, EPSILON);
         assert_eq!(out, Vec3::X);
-        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 0.9);
+        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 0.9, EPSILON);
         assert_eq!(out, Vec3::X);
     }
 
     #[test]
     fn does_not_snap_if_smoothness_is_one() {
         // Smoothness of one results in the value not changing, so it doesn't make sense to snap
-        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 1.0);
+        let out = lerp_and_snap_vec3(Vec3::X * 0.9991, Vec3::X, 1.0, EPSILON);
         assert_eq!(out, Vec3::X * 0.9991);
     }
+
+    #[test]
+    fn snaps_all_components() {
+        let out = lerp_and_snap_vec3(Vec3::splat(0.9995), Vec3::ONE, 0.5, EPSILON);
+        assert_eq!(out, Vec3::ONE);
+    }
+}
+
+#[cfg(test)]
+mod edge_pan_direction_tests {
+    use super::*;
+
+    #[test]
+    fn no_pan_away_from_edges() {
+        let direction = edge_pan_direction(Vec2::new(400.0, 300.0), Vec2::new(800.0, 600.0), 10.0);
+        assert_eq!(direction, Vec2::ZERO);
+    }
+
+    #[test]
+    fn pans_towards_edges() {
+        let size = Vec2::new(800.0, 600.0);
+        assert_eq!(
+            edge_pan_direction(Vec2::new(795.0, 2.0), size, 10.0),
+            Vec2::new(1.0, -1.0)
+        );
+        assert_eq!(
+            edge_pan_direction(Vec2::new(5.0, 300.0), size, 10.0),
+            Vec2::new(-1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn no_pan_outside_viewport() {
+        let direction = edge_pan_direction(Vec2::new(-5.0, 300.0), Vec2::new(800.0, 600.0), 10.0);
+        assert_eq!(direction, Vec2::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod min_beta_above_ground_tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn focus_on_ground_stays_level_or_above() {
+        assert!(approx_eq!(f32, min_beta_above_ground(0.0, 0.0, 10.0), 0.0));
+    }
+
+    #[test]
+    fn focus_above_ground_allows_looking_up() {
+        // Focus 5 above the ground with radius 10, so the camera can go 30 degrees below it
+        let min_beta = min_beta_above_ground(0.0, 5.0, 10.0);
+        assert!(approx_eq!(f32, min_beta, -PI / 6.0, epsilon = 1e-5));
+    }
+
+    #[test]
+    fn radius_shorter_than_height_has_no_limit() {
+        assert!(approx_eq!(
+            f32,
+            min_beta_above_ground(0.0, 5.0, 2.0),
+            -PI / 2.0
+        ));
+    }
+}
+
+#[cfg(test)]
+mod orbit_forward_tests {
+    use super::*;
+
+    #[test]
+    fn matches_camera_transform() {
+        let mut transform = Transform::default();
+        update_orbit_transform(
+            0.7,
+            0.4,
+            0.0,
+            3.0,
+            Vec3::ZERO,
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            &mut transform,
+        );
+        assert!(orbit_forward(0.7, 0.4, Quat::IDENTITY).abs_diff_eq(transform.forward(), 1e-5));
+    }
+}
+
+#[cfg(test)]
+mod combined_bounds_tests {
+    use super::*;
+
+    #[test]
+    fn no_aabbs_returns_none() {
+        assert_eq!(combined_bounds(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn combines_transformed_aabbs() {
+        let aabb = Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(1.0));
+        let a = GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0));
+        let b = GlobalTransform::from(Transform::from_scale(Vec3::splat(2.0)));
+        let (min, max) = combined_bounds([(&aabb, &a), (&aabb, &b)].into_iter()).unwrap();
+        assert!(min.abs_diff_eq(Vec3::splat(-2.0), 1e-5));
+        assert!(max.abs_diff_eq(Vec3::new(6.0, 2.0, 2.0), 1e-5));
+    }
+}
+
+#[cfg(test)]
+mod modifiers_select_tests {
+    use super::*;
+    use crate::Bindings;
+
+    fn selected(pan_orbit: &PanOrbitCamera, keys: &[KeyCode]) -> [bool; 3] {
+        let mut key_input = Input::<KeyCode>::default();
+        for key in keys {
+            key_input.press(*key);
+        }
+        [Action::Orbit, Action::Pan, Action::Zoom]
+            .map(|action| modifiers_select(pan_orbit, action, &key_input))
+    }
+
+    #[test]
+    fn blender_bindings_select_by_modifier() {
+        let pan_orbit = PanOrbitCamera {
+            bindings: Bindings::blender(),
+            ..PanOrbitCamera::default()
+        };
+        assert_eq!(selected(&pan_orbit, &[]), [true, false, false]);
+        assert_eq!(
+            selected(&pan_orbit, &[KeyCode::ShiftLeft]),
+            [false, true, false]
+        );
+        assert_eq!(
+            selected(&pan_orbit, &[KeyCode::ControlLeft]),
+            [false, false, true]
+        );
+    }
+
+    #[test]
+    fn most_specific_chord_wins() {
+        let pan_orbit = PanOrbitCamera {
+            bindings: Bindings {
+                modifier_orbit: Some(KeyCode::ControlLeft),
+                chord_pan: KeyChord::new(&[KeyCode::ControlLeft, KeyCode::ShiftLeft]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            selected(&pan_orbit, &[KeyCode::ControlLeft]),
+            [true, false, false]
+        );
+        assert_eq!(
+            selected(&pan_orbit, &[KeyCode::ControlLeft, KeyCode::ShiftLeft]),
+            [false, true, false]
+        );
+    }
+
+    #[test]
+    fn exclusive_modifiers_reject_other_modifiers() {
+        let pan_orbit = PanOrbitCamera {
+            bindings: Bindings {
+                exclusive_modifiers: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(selected(&pan_orbit, &[]), [true, true, true]);
+        assert_eq!(
+            selected(&pan_orbit, &[KeyCode::AltLeft]),
+            [false, false, false]
+        );
+    }
+}
+
+#[cfg(test)]
+mod pixels_to_world_pan_tests {
+    use super::*;
+    use bevy::prelude::{OrthographicProjection, PerspectiveProjection};
+    use bevy::render::camera::CameraProjection;
+
+    #[test]
+    fn scales_perspective_by_radius() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: 1.0,
+            aspect_ratio: 2.0,
+            ..Default::default()
+        });
+        let delta =
+            pixels_to_world_pan(Vec2::new(100.0, 50.0), &projection, Vec2::splat(100.0), 4.0);
+        assert!(delta.abs_diff_eq(Vec3::new(8.0, -2.0, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn uses_orthographic_area() {
+        let mut orthographic = OrthographicProjection::default();
+        orthographic.update(200.0, 100.0);
+        let projection = Projection::Orthographic(orthographic);
+        let delta = pixels_to_world_pan(
+            Vec2::new(10.0, 20.0),
+            &projection,
+            Vec2::new(200.0, 100.0),
+            50.0,
+        );
+        assert!(delta.abs_diff_eq(Vec3::new(10.0, -20.0, 0.0), 1e-5));
+    }
+}
+
+#[cfg(test)]
+mod move_towards_vec3_tests {
+    use super::*;
+
+    #[test]
+    fn limits_distance() {
+        let moved = move_towards_vec3(Vec3::ZERO, Vec3::new(3.0, 4.0, 0.0), 1.0);
+        assert!(moved.abs_diff_eq(Vec3::new(0.6, 0.8, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn does_not_overshoot() {
+        assert_eq!(move_towards_vec3(Vec3::ZERO, Vec3::X, 5.0), Vec3::X);
+    }
+}
+
+#[cfg(test)]
+mod drag_button_pressed_tests {
+    use super::*;
+    use crate::Bindings;
+
+    #[test]
+    fn ignores_modifiers() {
+        let pan_orbit = PanOrbitCamera {
+            bindings: Bindings {
+                modifier_orbit: Some(KeyCode::ShiftLeft),
+                ..Bindings::default()
+            },
+            ..PanOrbitCamera::default()
+        };
+        let mut mouse_input = Input::<MouseButton>::default();
+        assert!(!drag_button_pressed(&pan_orbit, &mouse_input));
+        mouse_input.press(pan_orbit.bindings.button_orbit);
+        assert!(drag_button_pressed(&pan_orbit, &mouse_input));
+    }
+}
+
+#[cfg(test)]
+mod transform_differs_tests {
+    use super::*;
+
+    #[test]
+    fn ignores_changes_within_epsilon() {
+        let a = Transform::from_xyz(1.0, 2.0, 3.0);
+        assert!(!transform_differs(&a, &a, 0.0));
+        assert!(!transform_differs(
+            &a,
+            &a.with_translation(Vec3::new(1.0, 2.0, 3.000001)),
+            0.00001
+        ));
+        assert!(transform_differs(
+            &a,
+            &a.with_translation(Vec3::new(1.0, 2.0, 3.1)),
+            0.00001
+        ));
+        assert!(transform_differs(
+            &a,
+            &a.with_rotation(Quat::from_rotation_y(0.01)),
+            0.00001
+        ));
+    }
+
+    #[test]
+    fn detects_small_rotations() {
+        let a = Transform::from_xyz(1.0, 2.0, 3.0);
+        let b = a.with_rotation(Quat::from_rotation_y(0.0001));
+        assert!(transform_differs(&a, &b, 0.00005));
+        assert!(!transform_differs(&a, &b, 0.0002));
+        assert!(transform_differs(&a, &b, 0.0));
+        // The same rotation, as the negated quaternion
+        let c = a.with_rotation(-a.rotation);
+        assert!(!transform_differs(&a, &c, 0.00001));
+    }
+}
+
+#[cfg(test)]
+mod arc_angles_tests {
+    use super::*;
+
+    #[test]
+    fn goes_over_the_top() {
+        // From the front to the back over the top, rather than around the side
+        let (alpha, beta) = arc_angles(0.0, PI / 4.0, PI, PI / 4.0, 0.5);
+        assert!((beta - PI / 2.0).abs() < 0.01);
+        assert!(alpha.is_finite());
+    }
+
+    #[test]
+    fn alpha_stays_continuous_over_the_pole() {
+        let steps = 1000;
+        let mut last_alpha = 0.0;
+        for i in 1..=steps {
+            let t = i as f32 / steps as f32;
+            let (alpha, _) = arc_angles(0.0, PI / 4.0, PI, PI / 4.0, t);
+            assert!((alpha - last_alpha).abs() < 0.2, "alpha jumped at t = {t}");
+            last_alpha = alpha;
+        }
+    }
+
+    #[test]
+    fn matches_the_ends() {
+        assert_eq!(arc_angles(0.1, 0.2, 3.0, -0.4, 0.0), (0.1, 0.2));
+        assert_eq!(arc_angles(0.1, 0.2, 3.0, -0.4, 1.0), (3.0, -0.4));
+    }
+}
+
+#[cfg(test)]
+mod update_upside_down_tests {
+    use super::*;
+
+    #[test]
+    fn flips_past_the_pole() {
+        assert!(!update_upside_down(false, 0.0, 0.1));
+        assert!(update_upside_down(false, PI, 0.1));
+        assert!(update_upside_down(false, -PI * 0.75, 0.1));
+        assert!(!update_upside_down(true, TAU, 0.1));
+    }
+
+    #[test]
+    fn keeps_state_within_hysteresis() {
+        let near_pole = PI / 2.0 + 0.05;
+        assert!(!update_upside_down(false, near_pole, 0.1));
+        assert!(update_upside_down(true, near_pole, 0.1));
+        assert!(update_upside_down(true, PI / 2.0 - 0.05, 0.1));
+        assert!(!update_upside_down(true, PI / 2.0 - 0.15, 0.1));
+    }
+}
+
+#[cfg(test)]
+mod logical_viewport_size_tests {
+    use super::*;
+    use bevy::render::camera::Viewport;
+    use bevy::window::WindowResolution;
+
+    #[test]
+    fn uses_window_scale_factor() {
+        // The resolution is physical pixels, so this is 400x300 logical pixels
+        let window = Window {
+            resolution: WindowResolution::new(800.0, 600.0).with_scale_factor_override(2.0),
+            ..Window::default()
+        };
+        let full = Camera::default();
+        assert_eq!(
+            logical_viewport_size(&full, &window),
+            Vec2::new(400.0, 300.0)
+        );
+        let split = Camera {
+            viewport: Some(Viewport {
+                physical_size: bevy::math::UVec2::new(400, 300),
+                ..Viewport::default()
+            }),
+            ..Camera::default()
+        };
+        assert_eq!(
+            logical_viewport_size(&split, &window),
+            Vec2::new(200.0, 150.0)
+        );
+    }
 }
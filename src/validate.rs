@@ -0,0 +1,128 @@
+use crate::{OrbitState, PanOrbitCamera};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Event sent when a `PanOrbitCamera` has NaN or infinite values, e.g. from dividing by a zero
+/// radius, or from values set by user code. Instead of writing them to the camera's transform,
+/// which would typically make the screen go black, the camera is reset to the last valid focus,
+/// alpha, beta, radius, roll, and scale it had, and a warning is logged.
+#[derive(Event, Reflect, Clone, Debug, PartialEq, Eq)]
+pub struct PanOrbitCameraError {
+    /// The camera that had invalid values.
+    pub camera: Entity,
+    /// The names of the fields that were invalid, e.g. `"target_radius"`. Not reflected, since
+    /// `&'static str` can't be.
+    #[reflect(ignore)]
+    pub invalid_fields: Vec<&'static str>,
+}
+
+/// The values a camera is reset to when it becomes invalid
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct ValidState {
+    state: OrbitState,
+    roll: f32,
+    scale: Option<f32>,
+}
+
+impl Default for ValidState {
+    fn default() -> Self {
+        ValidState {
+            state: OrbitState::new(Vec3::ZERO, 0.0, 0.0, 1.0),
+            roll: 0.0,
+            scale: None,
+        }
+    }
+}
+
+impl PanOrbitCamera {
+    /// The names of the fields that are NaN or infinite
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        let optional = |value: Option<f32>| value.is_none_or(f32::is_finite);
+        [
+            ("focus", self.focus.is_finite()),
+            ("alpha", optional(self.alpha)),
+            ("beta", optional(self.beta)),
+            ("radius", optional(self.radius)),
+            ("scale", optional(self.scale)),
+            ("roll", self.roll.is_finite()),
+            ("target_focus", self.target_focus.is_finite()),
+            ("target_alpha", self.target_alpha.is_finite()),
+            ("target_beta", self.target_beta.is_finite()),
+            ("target_radius", self.target_radius.is_finite()),
+            ("target_scale", self.target_scale.is_finite()),
+            ("target_roll", self.target_roll.is_finite()),
+        ]
+        .into_iter()
+        .filter(|(_, valid)| !valid)
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+/// Resets cameras with NaN or infinite values to their last valid state, before their transforms
+/// are updated
+pub(crate) fn validate_pan_orbit_cameras(
+    mut valid_states: Local<HashMap<Entity, ValidState>>,
+    mut error_events: EventWriter<PanOrbitCameraError>,
+    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera)>,
+    mut removed: RemovedComponents<PanOrbitCamera>,
+) {
+    for entity in removed.read() {
+        valid_states.remove(&entity);
+    }
+
+    for (entity, mut pan_orbit) in orbit_cameras.iter_mut() {
+        if !pan_orbit.initialized {
+            continue;
+        }
+        let invalid_fields = pan_orbit.invalid_fields();
+        if invalid_fields.is_empty() {
+            valid_states.insert(
+                entity,
+                ValidState {
+                    state: pan_orbit.orbit_state(),
+                    roll: pan_orbit.roll,
+                    scale: pan_orbit.scale,
+                },
+            );
+            continue;
+        }
+
+        warn!(
+            "PanOrbitCamera {:?} has invalid values for {}, resetting it to its last valid state",
+            entity,
+            invalid_fields.join(", ")
+        );
+        let valid = valid_states.get(&entity).copied().unwrap_or_default();
+        let state = valid.state;
+        pan_orbit.set_immediate(state.alpha, state.beta, state.radius, state.focus);
+        pan_orbit.roll = valid.roll;
+        pan_orbit.target_roll = valid.roll;
+        pan_orbit.scale = valid.scale;
+        pan_orbit.target_scale = valid.scale.unwrap_or(1.0);
+        error_events.send(PanOrbitCameraError {
+            camera: entity,
+            invalid_fields,
+        });
+    }
+}
+
+#[cfg(test)]
+mod invalid_fields_tests {
+    use super::*;
+
+    #[test]
+    fn default_camera_is_valid() {
+        assert!(PanOrbitCamera::default().invalid_fields().is_empty());
+    }
+
+    #[test]
+    fn reports_nan_and_infinite_fields() {
+        let camera = PanOrbitCamera {
+            radius: Some(f32::NAN),
+            target_focus: Vec3::new(0.0, f32::INFINITY, 0.0),
+            ..default()
+        };
+        assert_eq!(camera.invalid_fields(), vec!["radius", "target_focus"]);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::OrbitState;
+use bevy::prelude::*;
+
+/// Add this to an entity with `PanOrbitCamera` to get how fast the camera moved during the last
+/// frame, e.g. to drive motion blur, or a whoosh sound proportional to the camera's movement.
+/// This includes movement caused by smoothing, animation, etc., not just direct user input.
+/// All values are per second, and are zero while the camera is at rest. Updated automatically.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PanOrbitVelocity {
+    /// How fast alpha changed, in radians per second.
+    pub alpha: f32,
+    /// How fast beta changed, in radians per second.
+    pub beta: f32,
+    /// How fast roll changed, in radians per second.
+    pub roll: f32,
+    /// How fast the radius changed, in world units per second. Negative when zooming in.
+    pub radius: f32,
+    /// How fast the focus moved, in world units per second.
+    pub focus: Vec3,
+    /// How fast the camera itself moved, in world units per second.
+    pub translation: Vec3,
+}
+
+impl PanOrbitVelocity {
+    /// The velocities for moving from `before` to `after` in `delta_seconds`
+    pub(crate) fn between(
+        before: OrbitState,
+        after: OrbitState,
+        roll_delta: f32,
+        translation_delta: Vec3,
+        delta_seconds: f32,
+    ) -> Self {
+        if delta_seconds <= 0.0 {
+            return PanOrbitVelocity::default();
+        }
+        PanOrbitVelocity {
+            alpha: (after.alpha - before.alpha) / delta_seconds,
+            beta: (after.beta - before.beta) / delta_seconds,
+            roll: roll_delta / delta_seconds,
+            radius: (after.radius - before.radius) / delta_seconds,
+            focus: (after.focus - before.focus) / delta_seconds,
+            translation: translation_delta / delta_seconds,
+        }
+    }
+
+    /// The angular speed of the orbit, combining alpha and beta, in radians per second.
+    pub fn angular_speed(&self) -> f32 {
+        Vec2::new(self.alpha, self.beta).length()
+    }
+}
+
+#[cfg(test)]
+mod between_tests {
+    use super::*;
+
+    #[test]
+    fn divides_changes_by_time() {
+        let before = OrbitState::new(Vec3::ZERO, 0.0, 0.0, 10.0);
+        let after = OrbitState::new(Vec3::X, 0.5, 0.25, 9.0);
+        let velocity = PanOrbitVelocity::between(before, after, 0.1, Vec3::Y, 0.5);
+        assert_eq!(velocity.alpha, 1.0);
+        assert_eq!(velocity.beta, 0.5);
+        assert_eq!(velocity.roll, 0.2);
+        assert_eq!(velocity.radius, -2.0);
+        assert_eq!(velocity.focus, Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(velocity.translation, Vec3::new(0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn zero_time_gives_zero_velocity() {
+        let before = OrbitState::default();
+        let after = OrbitState::new(Vec3::X, 1.0, 1.0, 1.0);
+        let velocity = PanOrbitVelocity::between(before, after, 1.0, Vec3::X, 0.0);
+        assert_eq!(velocity, PanOrbitVelocity::default());
+    }
+}